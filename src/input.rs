@@ -1,442 +1,3645 @@
-#[cfg(feature = "mice-keyboard")]
-use winit::{
-    dpi::PhysicalPosition,
-    event::*,
-};
-use crate::input_code::*;
-use std::collections::HashMap;
-use std::{cmp::Eq, hash::Hash};
-#[cfg(not(feature = "glium-types"))]
-type Vec2 = (f32, f32);
-#[cfg(feature = "glium-types")]
-type Vec2 = glium_types::vectors::Vec2;
-fn v(a: f32, b: f32) -> Vec2 {
-    #[cfg(not(feature = "glium-types"))]
-    { (a, b) }
-    #[cfg(feature = "glium-types")]
-    { Vec2::new(a, b) }
-}
-/// Values are the current value of the action, if its pressed, if its released and the sub values
-/// that make up the current value.
-type ActionValue = (f32, bool, bool, Vec<(f32, Vec<f32>)>);
-/// Binds are the list of connected actions and its sub indices
-type BindHash<F> = Vec<(F, usize, usize)>;
-/// Binds are a list of actions and their bindings
-pub type Binds<F> = Vec<(F, Vec<Vec<InputCode>>)>;
-/// A struct that handles all your input needs once you've hooked it up to winit and gilrs.
-/// ```
-/// use gilrs::Gilrs;
-/// use winit::{event::*, application::*, window::*, event_loop::*};
-/// use winit_input_map::*;
-/// struct App {
-///     window: Option<Window>,
-///     input: InputMap<()>,
-///     gilrs: Gilrs
-/// }
-/// impl ApplicationHandler for App {
-///     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
-///         self.window = Some(event_loop.create_window(Window::default_attributes()).unwrap());
-///     }
-///     fn window_event(&mut self, event_loop: &ActiveEventLoop, _: WindowId, event: WindowEvent) {
-///         self.input.update_with_window_event(&event);
-///         if let WindowEvent::CloseRequested = &event { event_loop.exit() }
-///     }
-///     fn device_event(&mut self, _: &ActiveEventLoop, id: DeviceId, event: DeviceEvent) {
-///         self.input.update_with_device_event(id, &event);
-///     }
-///     fn about_to_wait(&mut self, _: &ActiveEventLoop) {
-///         self.input.update_with_gilrs(&mut self.gilrs);
-/// 
-///         // put your code here
-///
-///         self.input.init();
-///     }
-/// }
-/// ```
-pub struct InputMap<F: Hash + Copy> {
-    /// Stores what each input code previous press value and what action its bound to and its sub
-    /// indices 
-    bind_hash: HashMap<InputCode, BindHash<F>>,
-    /// f32s and each bound current val, 1st bool is pressed and 2nd bool is released.
-    action_val: HashMap<F, ActionValue>,
-    /// weather the window has focus and therefor, if it should recieve inputs
-    #[cfg(all(feature = "mice-keyboard", feature = "gamepad"))]
-    focus: bool,
-    /// The mouse position
-    #[cfg(feature = "mice-keyboard")]
-    pub mouse_pos: Vec2,
-    /// The last input event, even if it isn't in the binds. Useful for handling rebinding
-    pub recently_pressed: Option<InputCode>,
-    /// The text typed this loop
-    pub text_typed: Option<String>,
-    /// Since most values are from 0-1 reducing the mouse sensitivity will result in better
-    /// consistancy
-    #[cfg(feature = "mice-keyboard")]
-    pub mouse_scale: f32,
-    /// Since most values are from 0-1 reducing the scroll sensitivity will result in better
-    /// consistancy
-    #[cfg(feature = "mice-keyboard")]
-    pub scroll_scale: f32,
-    /// The minimum value something has to be at to count as being pressed. Values over 1 will
-    /// result in most buttons being unusable
-    pub press_sensitivity: f32
-}
-impl InputMap<()> { 
-    /// Use if you dont want to have any actions and binds. Will still have access to everything else.
-    pub fn empty() -> Self { Self::default() }
-}
-impl<F: Hash + Copy> Default for InputMap<F> {
-    fn default() -> Self {
-        Self {
-            press_sensitivity:  0.5,
-            #[cfg(feature = "mice-keyboard")]
-            mouse_scale:        0.02,
-            #[cfg(feature = "mice-keyboard")]
-            scroll_scale:       1.0,
-            #[cfg(feature = "mice-keyboard")]
-            mouse_pos:  v(0.0, 0.0),
-            recently_pressed:  None,
-            text_typed:        None,
-            bind_hash:  HashMap::<InputCode, BindHash<F>>::new(),
-            action_val: HashMap::<F, ActionValue>::new(),
-            #[cfg(all(feature = "mice-keyboard", feature = "gamepad"))]
-            focus: true,
-        }
-    }
-}
-impl<F: Hash + Copy + Eq> InputMap<F> {
-    /// Creates a new input system. Takes the action and a list of its associated binds. An action
-    /// will count as being pressed if any of the binds are pressed. A bind is a list of
-    /// `InputCode`s that need to all be pressed for the bind to count as being pressed.
-    ///
-    /// It's recommended to use the `input_map!` macro to reduce boilerplate
-    /// and increase readability.
-    /// ```
-    /// use Action::*;
-    /// use winit_input_map::*;
-    /// use winit::keyboard::KeyCode;
-    /// #[derive(Hash, PartialEq, Eq, Clone, Copy)]
-    /// enum Action {
-    ///     Forward,
-    ///     Back,
-    ///     Pos,
-    ///     Neg
-    /// }
-    /// // doesnt have to be the same ordered as the enum.
-    /// let input = InputMap::new(&vec![
-    ///     (Forward, vec![ vec![KeyCode::KeyW.into()] ]),
-    ///     (Pos,     vec![ vec![KeyCode::KeyA.into()] ]),
-    ///     (Back,    vec![ vec![KeyCode::KeyS.into()] ]),
-    ///     (Neg,     vec![ vec![KeyCode::KeyD.into()] ])
-    /// ]);
-    /// ```
-    pub fn new(binds: &Binds<F>) -> Self {
-        let mut result = Self::default();
-        result.add_binds(binds);
-        result
-    }
-    /// Takes binds and adds them to the currently existing map. The `binds!()` macro will help
-    /// reduce the boiler_plate of this function.
-    pub fn add_binds(&mut self, binds: &Binds<F>) {
-        for (action, binds) in binds {
-            for (bind_i, bind) in binds.iter().enumerate() {
-                self.action_val.entry(*action).or_default().3.push((0.0, vec![]));
-                for (code_i, code) in bind.iter().enumerate() {
-                    self.action_val.entry(*action).or_default().3[bind_i].1.push(0.0);
-                    self.bind_hash.entry(*code).or_default().push((*action, bind_i, code_i));
-                }
-            }
-        }
-        self.action_val.shrink_to_fit();
-        self.bind_hash.shrink_to_fit();
-    }
-    /// Removes all binds and then adds the inputed binds. The `binds!()` macro will help
-    /// reduce the boiler_plate of this function.
-    pub fn set_binds(&mut self, binds: &Binds<F>) {
-        self.bind_hash.clear();
-        self.add_binds(binds);
-    }
-    /// Returns the current binds of the InputMap, may not be in the same order as the inputed
-    /// binds.
-    pub fn get_binds(&self) -> Binds<F> {
-        let mut results = Vec::new();
-
-        for (input_code,  binds) in self.bind_hash.iter() {
-            for (action, binds_i, bind_i) in binds.iter() {
-                let action_i = if let Some(result) = results.iter().position(|(a, _)| a == action) { result }
-                else { results.push((*action, vec![])); results.len() - 1 };
-
-                let len = results[action_i].1.len();
-                if *binds_i >= len { results[action_i].1.append(&mut vec![vec![]; binds_i - len + 1]); }
-
-                let vec = &mut results[action_i].1[*binds_i];
-
-                let len = vec.len();
-                if *bind_i >= len { vec.append(&mut vec![*input_code; bind_i - len + 1]); }
-            }
-        }
-        results
-    }
-    /// Updates the input map using a winit event. Make sure to call `input.init()` when your done with
-    /// the input this loop.
-    /// ```no_run
-    /// use winit::{event::*, window::WindowAttributes, event_loop::EventLoop};
-    /// use winit_input_map::*;
-    ///
-    /// let mut event_loop = EventLoop::new().unwrap();
-    /// event_loop.set_control_flow(winit::event_loop::ControlFlow::Poll);
-    /// let _window = event_loop.create_window(WindowAttributes::default()).unwrap();
-    ///
-    /// let mut input = input_map!();
-    ///
-    /// event_loop.run(|event, target|{
-    ///     input.update_with_winit(&event);
-    ///     match &event{
-    ///         Event::WindowEvent { event: WindowEvent::CloseRequested, .. } => target.exit(),
-    ///         Event::AboutToWait => input.init(),
-    ///         _ => ()
-    ///     }
-    /// });
-    /// ```
-    #[cfg(feature = "mice-keyboard")]
-    #[deprecated = "use `update_with_window_event` and `update_with_device_event`"]
-    pub fn update_with_winit(&mut self, event: &Event<()>) {
-        match event {
-            Event::WindowEvent { event, .. } => self.update_with_window_event(event),
-            Event::DeviceEvent { event, device_id, .. } => self.update_with_device_event(*device_id, event),
-            _ => ()
-        }
-    }
-    #[cfg(feature = "mice-keyboard")]
-    pub fn update_with_device_event(&mut self, id: DeviceId, event: &DeviceEvent) {
-        use base_input_codes::*;
-        match event {
-            DeviceEvent::MouseMotion { delta } => {
-                let x = delta.0 as f32 * self.mouse_scale;
-                let y = delta.1 as f32 * self.mouse_scale;
-                self.modify_val(MouseMoveRight.with_id(id), |v| v + x.max(0.0));
-                self.modify_val(MouseMoveLeft .with_id(id), |v| v - x.min(0.0));
-                self.modify_val(MouseMoveDown .with_id(id), |v| v + y.max(0.0));
-                self.modify_val(MouseMoveUp   .with_id(id), |v| v - y.min(0.0));
-            },
-            DeviceEvent::MouseWheel { delta } => self.update_scroll(*delta, id),
-             _ => (),
-        }
-    }
-    #[cfg(feature = "mice-keyboard")]
-    pub fn update_with_window_event(&mut self, event: &WindowEvent) {
-        match event {
-            WindowEvent::CursorMoved { position, .. } => self.update_mouse(*position),
-            WindowEvent::MouseWheel { delta, device_id, .. } => self.update_scroll(*delta, *device_id),
-            WindowEvent::MouseInput { state, button, device_id } => self.update_buttons(state, *device_id, *button),
-            WindowEvent::KeyboardInput { event, device_id, .. } => self.update_keys(*device_id, event),
-            WindowEvent::Focused(false) => {
-                for val in self.action_val.values_mut() {
-                    val.3.iter_mut().for_each(|i| { i.0 = 0.0; i.1.iter_mut().for_each(|i| *i = 0.0) });
-                    val.0 = 0.0;
-                }
-                #[cfg(feature = "gamepad")] { self.focus = false; }
-            },
-            #[cfg(feature = "gamepad")]
-            WindowEvent::Focused(true) => self.focus = true,
-            _ => ()
-        }
-    }
-    #[cfg(feature = "gamepad")]
-    pub fn update_with_gilrs(&mut self, gilrs: &mut gilrs::Gilrs) {
-        while let Some(ev) = gilrs.next_event() {
-            if self.focus { self.update_gamepad(ev); }
-        }
-    }
-    /// Makes the input map ready to recieve new events.
-    pub fn init(&mut self) {
-        #[cfg(feature = "mice-keyboard")]
-        {
-            use base_input_codes::*;
-            for i in [MouseMoveLeft, MouseMoveRight,   
-            MouseMoveUp, MouseMoveDown, MouseScrollUp,
-            MouseScrollDown, MouseScrollLeft, 
-            MouseScrollRight] {
-                self.update_val(i.into(), 0.0);
-            }
-        }
-        self.action_val.values_mut().for_each(|(_, p, r, _)| (*p, *r) = (false, false));
-        self.recently_pressed = None;
-        self.text_typed = None;
-    }
-    #[cfg(feature = "mice-keyboard")]
-    fn update_scroll(&mut self, delta: MouseScrollDelta, id: DeviceId) {
-        use base_input_codes::*;
-        let (x, y) = match delta {
-        MouseScrollDelta::LineDelta(x, y) => (x, y),
-            MouseScrollDelta::PixelDelta(PhysicalPosition { x, y }) => (x as f32, y as f32)
-        };
-        let (x, y) = (x * self.scroll_scale, y * self.scroll_scale);
-        
-        self.modify_val(MouseScrollUp.with_id(id),    |v| v + y.max(0.0));
-        self.modify_val(MouseScrollDown.with_id(id),  |v| v - y.min(0.0));
-        self.modify_val(MouseScrollLeft.with_id(id),  |v| v + x.max(0.0));
-        self.modify_val(MouseScrollRight.with_id(id), |v| v - x.min(0.0));
-    }
-    #[cfg(feature = "mice-keyboard")]
-    fn update_mouse(&mut self, position: PhysicalPosition<f64>) {
-        self.mouse_pos = v(position.x as f32, position.y as f32);
-    }
-    #[cfg(feature = "mice-keyboard")]
-    fn update_keys(&mut self, id: DeviceId, event: &KeyEvent) {
-        let input_code: DeviceInput = event.physical_key.into();
-
-        if let (Some(string), Some(new)) = (&mut self.text_typed, &event.text) {
-            string.push_str(new);
-        } else { self.text_typed = event.text.as_ref().map(|i| i.to_string()) }
-
-        self.update_val(input_code.with_id(id), event.state.is_pressed().into());
-    }
-    #[cfg(feature = "mice-keyboard")]
-    fn update_buttons(&mut self, state: &ElementState, id: DeviceId, button: MouseButton) {
-        let input_code: DeviceInput = button.into();
-        self.update_val(input_code.with_id(id), state.is_pressed().into());
-    }
-    /// updates provided input code
-    fn update_val(&mut self, input_code: InputCode, val: f32) {
-        self.modify_val(input_code, |_| val);
-    }
-    fn modify_val<FN: Fn(f32) -> f32>(&mut self, input_code: InputCode, f: FN) {
-        self.modify_single_val(input_code, &f);
-        self.modify_single_val(input_code.set_any(), f);
-    }
-    /// doesnt update both generic ids and specified ids, use `update_val` or `modify_val` for that
-    fn modify_single_val<FN: Fn(f32) -> f32>(&mut self, input_code: InputCode, f: FN) {
-        let Some(binds) = self.bind_hash.get(&input_code) else {
-            if f(0.0) >= self.press_sensitivity && !input_code.is_any() { self.recently_pressed = Some(input_code) }
-            return;
-        };
-
-        for &(action, index, sub_index) in binds {
-            let (curr_val, pressing, releasing, sub_values) = &mut self.action_val.get_mut(&action).unwrap();
-            
-            let old_sub_sub_val = sub_values[index].1[sub_index];
-            let new_sub_sub_val = f(old_sub_sub_val);
-            let change = new_sub_sub_val / old_sub_sub_val;
-            sub_values[index].1[sub_index] = new_sub_sub_val;
-
-            let sub_value = sub_values[index].0;
-            let new_sub_val = if change.is_finite() { sub_value * change }
-                else { sub_values[index].1.iter().fold(1.0, |a, b| a * b) };
-            sub_values[index].0 = new_sub_val;
-
-            *curr_val += new_sub_val - sub_value;
-
-            let now_pressing = *curr_val >= self.press_sensitivity;
-            if now_pressing && !input_code.is_any() { self.recently_pressed = Some(input_code) }
-
-            let jpressed = now_pressing && !*pressing;
-            let released = !now_pressing && *pressing;
-            *pressing = jpressed;
-            *releasing = released;
-        }
-    }
-    #[cfg(feature = "gamepad")]
-    fn update_gamepad(&mut self, event: gilrs::Event) {
-        let gilrs::Event { id, event, .. } = event;
-        use crate::input_code::{axis_pos, axis_neg};
-        use gilrs::ev::EventType;
-        match event {
-            EventType::ButtonChanged(b, v, _) => {
-                let a: GamepadInput = b.into();
-                self.update_val(a.with_id(id), v);
-            },
-            EventType::AxisChanged(b, v, _) => {
-                let dir_pos = v.max(0.0);
-                let dir_neg = (-v).max(0.0);
-                let input_pos = axis_pos(b);
-                let input_neg = axis_neg(b);
-
-                self.update_val(input_pos.with_id(id), dir_pos);
-                self.update_val(input_neg.with_id(id), dir_neg);
-            },
-            EventType::Disconnected => {
-                // reset input
-
-                use GamepadInput::*;
-                for i in [LeftStickLeft, LeftStickRight, LeftStickUp, LeftStickDown, LeftStickPress,
-                 RightStickLeft, RightStickRight, RightStickUp, RightStickDown,
-                 RightStickPress, DPadLeft, DPadRight, DPadUp, DPadDown, LeftZ, RightZ,
-                 South, East, North, West, LeftBumper, LeftTrigger, RightBumper,
-                 RightTrigger,  Select, Start, Mode, Other].iter() {
-                    self.update_val(i.with_id(id), 0.0);
-                 }
-            }
-            _ => ()
-        }
-    }
-    /// Checks if action is being pressed currently based on the `press_sensitivity`.
-    /// same as `self.value(action) >= self.press_sensitivty`.
-    pub fn pressing(&self, action: F) -> bool {
-        self.value(action) >= self.press_sensitivity
-    }
-    /// Checks how much an action is being pressed. May be higher than 1 in the case of scroll
-    /// wheels, mouse movement or when multiple binds are bound to an action.
-    pub fn value(&self, action: F) -> f32 {
-        if let Some(&(v, _, _, _)) = self.action_val.get(&action) { v } else {  0.0  }
-    }
-    /// Checks if action was just pressed.
-    pub fn pressed(&self, action: F) -> bool {
-        if let Some(&(_, v, _, _)) = self.action_val.get(&action) { v } else { false }
-    }
-    /// Checks if action was just released.
-    pub fn released(&self, action: F) -> bool {
-        if let Some(&(_, _, v, _)) = self.action_val.get(&action) { v } else { false }
-    }
-    /// Returns f32 based on how much pos and neg are pressed. may return values higher than 1.0 in
-    /// the case of mouse movement and scrolling. usefull for movement controls. for 2d values see
-    /// `dir` and `dir_max_len_1`
-    /// ```no_test
-    /// let move_dir = input.axis(Neg, Pos);
-    /// ```
-    /// same as `input.value(pos) - input.value(neg)`
-    pub fn axis(&self, pos: F, neg: F) -> f32 {
-        self.value(pos) - self.value(neg)
-    }
-    /// Returns a vector based off of the x and y axis. Can return values with a length higher than
-    /// 1, if this is undesirable see `dir_max_len_1`.
-    pub fn dir(&self, pos_x: F, neg_x: F, pos_y: F, neg_y: F) -> Vec2 {
-        v(self.axis(pos_x, neg_x), self.axis(pos_y, neg_y))
-    }
-    /// Returns a vector based off of x and y axis with a maximum length of 1 (the same as a normalised
-    /// vector). If this undesirable see `dir`.
-    pub fn dir_max_len_1(&self, pos_x: F, neg_x: F, pos_y: F, neg_y: F) -> Vec2 {
-        let (x, y) = (self.axis(pos_x, neg_x), self.axis(pos_y, neg_y));
-        // if lower than 1, set to 1. since x/1 = x, that means anything lower than 1 is left unchanged
-        let length = (x*x + y*y).sqrt().max(1.0);
-        v(x/length, y/length)
-    }
-}
-/*#[cfg(test)]
-mod tests {
-    use super::*;
-    use test::Bencher;
-    extern crate test;
-    #[bench]
-    fn bench_input(b: &mut Bencher) {
-        use Action::*;
-        #[derive(PartialEq, Eq, Clone, Copy, Hash)]
-        enum Action {
-            Test1,
-            Test2,
-            Test3,
-            Test4,
-        }
-        let mut input = { use base_input_codes::*; crate::input_map!(
-            (Test1, KeyI, (ControlLeft, KeyZ), (ShiftLeft, KeyZ)),
-            (Test2, (ControlLeft, KeyS), KeyZ, KeyU),
-            (Test3, (KeyX, KeyI), (KeyS, KeyV, ControlLeft, KeyZ)),
-            (Test4, KeyZ)
-        ) };
-        
-        b.iter(|| {
-            input.update_val(base_input_codes::KeyZ.into(), 1.0);
-            input.update_val(base_input_codes::ControlLeft.into(), 1.0);
-        });
-    }
-}*/
+#[cfg(feature = "mice-keyboard")]
+use winit::{
+    dpi::PhysicalPosition,
+    event::*,
+};
+use crate::input_code::*;
+use std::collections::VecDeque;
+use std::{cmp::Eq, hash::Hash, time::Duration};
+/// The map used internally, with the `deterministic-hash` feature it iterates in the same order
+/// every run which is useful for snapshot tests. The `fast-hash` feature uses FxHash instead,
+/// which is much faster for small keys like `InputCode` and is also deterministic.
+#[cfg(not(any(feature = "deterministic-hash", feature = "fast-hash")))]
+type Map<K, V> = std::collections::HashMap<K, V>;
+#[cfg(all(feature = "deterministic-hash", not(feature = "fast-hash")))]
+type Map<K, V> = std::collections::HashMap<K, V, std::hash::BuildHasherDefault<std::collections::hash_map::DefaultHasher>>;
+#[cfg(feature = "fast-hash")]
+type Map<K, V> = rustc_hash::FxHashMap<K, V>;
+/// A 2d vector that can be made from an x and y, so every function that returns a vector, like
+/// `dir`, `mouse_pos` and `left_stick`, can return the vector type of any math library. Its implemented for tuples,
+/// arrays and, with their features, `glium-types`, `glam` and `mint` vectors. The features can
+/// be used together. Implement it for your own vector type to use it.
+/// ```ignore
+/// impl Vec2Like for MyVec2 {
+///     fn new(x: f32, y: f32) -> Self { MyVec2 { x, y } }
+/// }
+/// let dir: MyVec2 = input.dir(Right, Left, Up, Down);
+/// ```
+pub trait Vec2Like {
+    fn new(x: f32, y: f32) -> Self;
+}
+impl Vec2Like for (f32, f32) {
+    fn new(x: f32, y: f32) -> Self { (x, y) }
+}
+impl Vec2Like for [f32; 2] {
+    fn new(x: f32, y: f32) -> Self { [x, y] }
+}
+#[cfg(feature = "glium-types")]
+impl Vec2Like for glium_types::vectors::Vec2 {
+    fn new(x: f32, y: f32) -> Self { glium_types::vectors::Vec2::new(x, y) }
+}
+#[cfg(feature = "glam")]
+impl Vec2Like for glam::Vec2 {
+    fn new(x: f32, y: f32) -> Self { glam::Vec2::new(x, y) }
+}
+#[cfg(feature = "mint")]
+impl Vec2Like for mint::Vector2<f32> {
+    fn new(x: f32, y: f32) -> Self { mint::Vector2 { x, y } }
+}
+/// Zeroes vectors shorter than the deadzone and rescales the rest so they start from 0 at the
+/// edge of the deadzone, clamping the length to 1.
+fn radial_deadzone(x: f32, y: f32, deadzone: f32) -> (f32, f32) {
+    let length = (x*x + y*y).sqrt();
+    if length <= deadzone || length == 0.0 { return (0.0, 0.0) }
+    let scale = ((length - deadzone) / (1.0 - deadzone)).min(1.0) / length;
+    (x * scale, y * scale)
+}
+/// The value of a bind from the value of each of its input codes. Binds with multiple codes clamp
+/// each code to 1 before multiplying them so things like mouse movement in a bind with a key can't
+/// make it go over what the mouse movement would give on its own.
+fn bind_value(codes: &[f32]) -> f32 {
+    if let [code] = codes { *code } else { codes.iter().map(|v| v.min(1.0)).product() }
+}
+/// The value of an action from the value of each of its binds
+fn combine(sub_values: &[(f32, Vec<f32>)], mode: CombineMode) -> f32 {
+    match mode {
+        CombineMode::Sum => sub_values.iter().map(|(v, _)| v).sum(),
+        CombineMode::Max => sub_values.iter().fold(0.0, |max, (v, _)| max.max(*v))
+    }
+}
+/// Values are the current value of the action, if its pressed, if its released and the sub values
+/// that make up the current value.
+type ActionValue = (f32, bool, bool, Vec<(f32, Vec<f32>)>);
+/// Simultaneous binds are whether they're active and the loop each input code was pressed on
+type Chord = (bool, Vec<Option<u64>>);
+/// Binds are the list of connected actions and its sub indices
+type BindHash<F> = Vec<(F, usize, usize)>;
+/// Binds are a list of actions and their bindings
+pub type Binds<F> = Vec<(F, Vec<Vec<InputCode>>)>;
+/// The layer binds are in unless they're added with `InputMap::add_layer_binds`
+pub const DEFAULT_LAYER: &str = "default";
+/// How the values of an actions binds are combined into its value, see
+/// `InputMap::set_combine_mode`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum CombineMode {
+    /// the values of every bind are added together so they can stack past 1
+    #[default]
+    Sum,
+    /// the value of the most pressed bind is used
+    Max
+}
+/// How `InputMap::axis_socd` resolves both directions of an axis being pressed at once
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum SocdMode {
+    /// both directions cancel out, giving 0
+    #[default]
+    Neutral,
+    /// the direction pressed most recently is used
+    LastWins,
+    /// the positive direction is used, e.g. up over down
+    PositivePriority
+}
+/// A copy of the value of every action made with `InputMap::snapshot`. Can be put back with
+/// `InputMap::restore` or compared with another snapshot using `diff`.
+#[derive(Debug, Clone)]
+pub struct InputSnapshot<F: Hash + Copy> {
+    action_val: Map<F, ActionValue>
+}
+impl<F: Hash + Copy + Eq> InputSnapshot<F> {
+    /// The value the action had when the snapshot was made.
+    pub fn value(&self, action: F) -> f32 {
+        if let Some(&(v, _, _, _)) = self.action_val.get(&action) { v } else { 0.0 }
+    }
+    /// Lists every action whose value is different in `other`, alongside its value in this
+    /// snapshot and then its value in `other`. Useful for debugging desyncs and only sending
+    /// the actions that changed.
+    pub fn diff(&self, other: &InputSnapshot<F>) -> Vec<(F, f32, f32)> {
+        let actions = self.action_val.keys().chain(other.action_val.keys().filter(|a| !self.action_val.contains_key(a)));
+        actions.filter_map(|&action| {
+            let (old, new) = (self.value(action), other.value(action));
+            if old != new { Some((action, old, new)) } else { None }
+        }).collect()
+    }
+}
+/// Creates a `Gilrs` that also uses the inputed SDL_GameControllerDB mappings, one mapping per
+/// line. Useful for supporting obscure controllers gilrs doesn't recognise. Mappings are also read
+/// from the `SDL_GAMECONTROLLERCONFIG` enviroment variable.
+/// ```no_run
+/// use winit_input_map::*;
+/// let mapping = "030000005e040000130b000011050000,Xbox Controller,a:b0,b:b1,x:b3,y:b4,platform:Linux,";
+/// let mut gilrs = gilrs_with_mappings(mapping).unwrap();
+/// let mut input = input_map!();
+/// input.update_with_gilrs(&mut gilrs);
+/// ```
+#[cfg(feature = "gamepad")]
+#[allow(clippy::result_large_err)]
+pub fn gilrs_with_mappings(mappings: &str) -> Result<gilrs::Gilrs, gilrs::Error> {
+    gilrs::GilrsBuilder::new().add_mappings(mappings).build()
+}
+/// A struct that handles all your input needs once you've hooked it up to winit and gilrs.
+/// ```
+/// use gilrs::Gilrs;
+/// use winit::{event::*, application::*, window::*, event_loop::*};
+/// use winit_input_map::*;
+/// struct App {
+///     window: Option<Window>,
+///     input: InputMap<()>,
+///     gilrs: Gilrs
+/// }
+/// impl ApplicationHandler for App {
+///     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+///         self.window = Some(event_loop.create_window(Window::default_attributes()).unwrap());
+///     }
+///     fn window_event(&mut self, event_loop: &ActiveEventLoop, _: WindowId, event: WindowEvent) {
+///         self.input.update_with_window_event(&event);
+///         if let WindowEvent::CloseRequested = &event { event_loop.exit() }
+///     }
+///     fn device_event(&mut self, _: &ActiveEventLoop, id: DeviceId, event: DeviceEvent) {
+///         self.input.update_with_device_event(id, &event);
+///     }
+///     fn about_to_wait(&mut self, _: &ActiveEventLoop) {
+///         self.input.update_with_gilrs(&mut self.gilrs);
+/// 
+///         // put your code here
+///
+///         self.input.init();
+///     }
+/// }
+/// ```
+pub struct InputMap<F: Hash + Copy> {
+    /// Stores what each input code previous press value and what action its bound to and its sub
+    /// indices 
+    bind_hash: Map<InputCode, BindHash<F>>,
+    /// f32s and each bound current val, 1st bool is pressed and 2nd bool is released.
+    action_val: Map<F, ActionValue>,
+    /// the value of every input code thats not 0, bound or not
+    code_values: Map<InputCode, f32>,
+    /// the rate each integrating action fills at and how much it has been filled
+    integrating: Map<F, (f32, f32)>,
+    /// how fast each smoothed action moves towards its value per second and where its at, both
+    /// already scaled by `global_scale`
+    smoothing: Map<F, (f32, f32)>,
+    /// the total time passed through `update_with_dt` and the time passed in the last call
+    time: (Duration, Duration),
+    /// the time each action was last pressed at
+    press_times: Map<F, Duration>,
+    /// how many times each action was pressed this loop
+    press_counts: Map<F, u32>,
+    /// the last input code from a specific device or gamepad and the one that last pressed each
+    /// action
+    press_sources: (Option<InputCode>, Map<F, InputCode>),
+    /// how many actions have been pressed and how many had been when each action was last pressed
+    press_order: (u64, Map<F, u64>),
+    /// how many binds have been pressed and how many had been when each bind was last pressed
+    bind_press_order: (u64, Map<(F, usize), u64>),
+    /// how many times per second each turbo action pulses and the time since its last pulse
+    turbo: Map<F, (f32, f32)>,
+    /// how many times `init` has been called
+    loop_count: u64,
+    /// the state of each simultaneous bind
+    chords: Map<(F, usize), Chord>,
+    /// how each action that doesn't add its binds together combines them
+    combine_modes: Map<F, CombineMode>,
+    /// the group of each bind thats in one
+    bind_groups: Map<(F, usize), u32>,
+    /// what the value of each bind with a scale is multiplied by
+    bind_scales: Map<(F, usize), f32>,
+    /// groups whose binds dont do anything
+    disabled_groups: Vec<u32>,
+    /// the layer of each bind thats not in `DEFAULT_LAYER`
+    bind_layers: Map<(F, usize), &'static str>,
+    /// layers whose binds dont do anything
+    disabled_layers: Vec<&'static str>,
+    /// how many binds have to be pressed at once for each action to count as pressed
+    min_binds: Map<F, usize>,
+    /// the `press_sensitivity` of actions that have their own
+    action_sensitivity: Map<F, f32>,
+    /// actions that stay pressed until they're consumed
+    sticky: Vec<F>,
+    /// the value of each action that changed this loop from before it changed
+    start_values: Map<F, f32>,
+    /// each action whose value changed with its old and new value, `None` when its turned off
+    changes: Option<Vec<(F, f32, f32)>>,
+    /// the highest value each action reached since it was last pressed
+    peaks: Map<F, f32>,
+    /// the actions pressed in each previous loop, newest first
+    history: VecDeque<Vec<F>>,
+    /// how many loops of history to keep, 0 disables it
+    history_capacity: usize,
+    /// weather `value` is clamped from 0 to 1
+    clamp_to_unit: bool,
+    /// weather the window has focus and therefor, if it should recieve inputs
+    #[cfg(feature = "mice-keyboard")]
+    focus: bool,
+    /// the loop and time the window last gained focus at
+    #[cfg(feature = "mice-keyboard")]
+    focus_gained: Option<(u64, Duration)>,
+    /// How long mouse movement is ignored for after the window gains focus, so the cursor jumping
+    /// while the window was unfocused doesn't spin the camera. Its always ignored for the loop
+    /// focus was gained in. Requires `update_with_dt` to be called every loop
+    #[cfg(feature = "mice-keyboard")]
+    pub focus_mouse_delay: Duration,
+    /// the mouse position in physical pixels
+    #[cfg(feature = "mice-keyboard")]
+    mouse_pos: (f32, f32),
+    /// how far the mouse wheel scrolled this loop, before `scroll_scale`
+    #[cfg(feature = "mice-keyboard")]
+    scroll_delta: (f32, f32),
+    /// how far the mouse moved this loop
+    #[cfg(feature = "mice-keyboard")]
+    mouse_delta: (f32, f32),
+    /// The last input event, even if it isn't in the binds. Useful for handling rebinding
+    pub recently_pressed: Option<InputCode>,
+    /// every input code that was pressed this loop in order
+    recent_inputs: Vec<InputCode>,
+    /// the action and bind thats going to be set to the next input pressed and the inputs that
+    /// cancel it and clear the bind
+    rebinding: Option<(F, usize, Vec<InputCode>, Vec<InputCode>)>,
+    /// the inputs that finished a `begin_rebind`, which are kept from every bind until they're
+    /// let go
+    swallowed: Vec<InputCode>,
+    /// the kind of device that last had an input pressed
+    active_input_kind: InputKind,
+    /// The text typed this loop
+    pub text_typed: Option<String>,
+    /// Since most values are from 0-1 reducing the mouse sensitivity will result in better
+    /// consistancy
+    #[cfg(feature = "mice-keyboard")]
+    pub mouse_scale: f32,
+    /// Since most values are from 0-1 reducing the scroll sensitivity will result in better
+    /// consistancy
+    #[cfg(feature = "mice-keyboard")]
+    pub scroll_scale: f32,
+    /// Makes `MouseMove` input codes give how fast the mouse is moving per second instead of how
+    /// far it moved this loop, so the value is the same no matter the frame rate. Requires
+    /// `update_with_dt` to be called after the other update functions every loop. `mouse_scale`
+    /// will likely need lowering since the values will be much bigger. Movement made to accumulate
+    /// with `set_accumulating` is left as a distance
+    #[cfg(feature = "mice-keyboard")]
+    pub mouse_as_rate: bool,
+    /// How much faster mouse movements are sped up, multiplying the movement by
+    /// `1 + mouse_acceleration * distance moved`. 0 turns it off, giving raw 1:1 movement
+    #[cfg(feature = "mice-keyboard")]
+    pub mouse_acceleration: f32,
+    /// The most `mouse_acceleration` can multiply mouse movement by
+    #[cfg(feature = "mice-keyboard")]
+    pub mouse_acceleration_max: f32,
+    /// Flips the horizontal mouse movement so `MouseMoveLeft` and `MouseMoveRight` swap
+    #[cfg(feature = "mice-keyboard")]
+    pub invert_mouse_x: bool,
+    /// Flips the vertical mouse movement so `MouseMoveUp` and `MouseMoveDown` swap. Useful for
+    /// inverted camera controls
+    #[cfg(feature = "mice-keyboard")]
+    pub invert_mouse_y: bool,
+    /// the size of the window in pixels
+    #[cfg(feature = "mice-keyboard")]
+    window_size: (f32, f32),
+    /// weather the cursor is inside the window
+    #[cfg(feature = "mice-keyboard")]
+    cursor_in_window: bool,
+    /// the scale factor of the window, used to convert physical pixels to logical pixels
+    #[cfg(feature = "mice-keyboard")]
+    scale_factor: f32,
+    /// mouse movement and scroll inputs that aren't reset every loop
+    #[cfg(feature = "mice-keyboard")]
+    accumulating: Vec<DeviceInput>,
+    /// how far each mouse move input code moved this loop and the time passed this loop, used to
+    /// work out the rate for `mouse_as_rate`
+    #[cfg(feature = "mice-keyboard")]
+    mouse_moved: (Map<InputCode, f32>, f32),
+    /// every device that has sent input, in the order they first did
+    #[cfg(feature = "mice-keyboard")]
+    devices: Vec<DeviceId>,
+    /// where the mouse was when each action was last pressed and the furthest its been from there
+    /// while the action was held
+    #[cfg(feature = "mice-keyboard")]
+    press_positions: Map<F, ((f32, f32), f32)>,
+    /// How many pixels the mouse can move between pressing and releasing for it to count as
+    /// being `tapped`
+    #[cfg(feature = "mice-keyboard")]
+    pub tap_distance: f32,
+    /// each piece of text typed this loop and the key that typed it, `None` for IME text
+    #[cfg(feature = "mice-keyboard")]
+    typed: Vec<(Option<winit::keyboard::PhysicalKey>, String)>,
+    /// the character each held key typed when it was pressed
+    #[cfg(feature = "mice-keyboard")]
+    held_chars: Map<winit::keyboard::PhysicalKey, char>,
+    /// every key being held and the device its from
+    #[cfg(feature = "mice-keyboard")]
+    held_keys: Vec<(DeviceId, winit::keyboard::PhysicalKey)>,
+    /// the text being composed with an IME and its cursor
+    #[cfg(feature = "mice-keyboard")]
+    ime_preedit: Option<(String, Option<(usize, usize)>)>,
+    /// where and when each current touch started
+    #[cfg(feature = "mice-keyboard")]
+    touches: Map<u64, ((f32, f32), Duration)>,
+    /// swipes that haven't been taken yet
+    #[cfg(feature = "mice-keyboard")]
+    swipes: Vec<SwipeDirection>,
+    /// How many pixels a touch has to move for it to count as a swipe
+    #[cfg(feature = "mice-keyboard")]
+    pub swipe_distance: f32,
+    /// How many pixels per second a touch has to move for it to count as a swipe. Requires
+    /// `update_with_dt` to be called every loop
+    #[cfg(feature = "mice-keyboard")]
+    pub swipe_speed: f32,
+    /// the kind and name of each gamepad that has sent events
+    #[cfg(feature = "gamepad")]
+    gamepads: Map<gilrs::GamepadId, (GamepadKind, String)>,
+    /// the raw left stick x and y then the right stick x and y of each gamepad
+    #[cfg(feature = "gamepad")]
+    sticks: Map<gilrs::GamepadId, [f32; 4]>,
+    /// How far a stick has to be moved before its binds, `left_stick` and `right_stick` stop
+    /// returning 0. Uses the distance the stick is pushed in any direction so diagonals aren't
+    /// clipped more than straight movement. Can be overriden for each gamepad with `set_gamepad_deadzone`
+    #[cfg(feature = "gamepad")]
+    pub stick_deadzone: f32,
+    /// gamepads whose events are ignored
+    #[cfg(feature = "gamepad")]
+    disabled_gamepads: Vec<gilrs::GamepadId>,
+    /// the stick deadzone of gamepads that dont use `stick_deadzone`
+    #[cfg(feature = "gamepad")]
+    gamepad_deadzones: Map<gilrs::GamepadId, f32>,
+    /// the value of each gamepads buttons
+    #[cfg(feature = "gamepad")]
+    button_values: Map<(gilrs::GamepadId, GamepadInput), f32>,
+    /// rumble effects that are playing and when they finish, effects stop when dropped
+    #[cfg(feature = "gamepad")]
+    rumbles: Vec<(gilrs::ff::Effect, std::time::Instant)>,
+    /// when gilrs recieved the last gamepad event that was used
+    #[cfg(feature = "gamepad")]
+    gamepad_event_time: Option<std::time::SystemTime>,
+    /// Overrides which `GamepadInput` a gilrs button is read as. Useful for fixing controllers
+    /// that report their buttons wrong. Buttons that aren't in the map use the default mapping
+    #[cfg(feature = "gamepad")]
+    pub button_remap: Map<gilrs::Button, GamepadInput>,
+    /// Weather events from winit and gilrs are used. Turning it off freezes the input from real
+    /// devices, e.g. for replays, while `restore` and `decode_frame` still work. Releases are still
+    /// used so nothing is stuck held once its turned back on, as are changes to the window's size,
+    /// focus and if the cursor is in it. See `set_enabled`
+    pub accept_input: bool,
+    /// The minimum value something has to be at to count as being pressed. Values over 1 will
+    /// result in most buttons being unusable. Use `set_press_sensitivity` to change it while
+    /// actions are held
+    pub press_sensitivity: f32,
+    /// Multiplies the value of every action, useful as an input strength setting for players with
+    /// a limited range of motion. Since actions are pressed when their scaled value reaches the
+    /// `press_sensitivity`, raising it makes partial presses count as pressed sooner. Defaults to 1
+    pub global_scale: f32
+}
+impl InputMap<()> { 
+    /// Use if you dont want to have any actions and binds. Will still have access to everything else.
+    pub fn empty() -> Self { Self::default() }
+}
+impl<F: Hash + Copy> Default for InputMap<F> {
+    fn default() -> Self {
+        Self {
+            press_sensitivity:  0.5,
+            global_scale: 1.0,
+            accept_input:       true,
+            #[cfg(feature = "mice-keyboard")]
+            mouse_scale:        0.02,
+            #[cfg(feature = "mice-keyboard")]
+            scroll_scale:       1.0,
+            #[cfg(feature = "mice-keyboard")]
+            mouse_as_rate:      false,
+            #[cfg(feature = "mice-keyboard")]
+            mouse_acceleration: 0.0,
+            #[cfg(feature = "mice-keyboard")]
+            mouse_acceleration_max: 4.0,
+            #[cfg(feature = "mice-keyboard")]
+            invert_mouse_x:     false,
+            #[cfg(feature = "mice-keyboard")]
+            invert_mouse_y:     false,
+            #[cfg(feature = "mice-keyboard")]
+            mouse_pos:  (0.0, 0.0),
+            #[cfg(feature = "mice-keyboard")]
+            scroll_delta: (0.0, 0.0),
+            #[cfg(feature = "mice-keyboard")]
+            mouse_delta: (0.0, 0.0),
+            #[cfg(feature = "mice-keyboard")]
+            window_size: (0.0, 0.0),
+            #[cfg(feature = "mice-keyboard")]
+            cursor_in_window: false,
+            #[cfg(feature = "mice-keyboard")]
+            accumulating: Vec::new(),
+            #[cfg(feature = "mice-keyboard")]
+            mouse_moved: (Map::default(), 0.0),
+            #[cfg(feature = "mice-keyboard")]
+            scale_factor: 1.0,
+            #[cfg(feature = "mice-keyboard")]
+            devices: Vec::new(),
+            #[cfg(feature = "mice-keyboard")]
+            press_positions: Map::default(),
+            #[cfg(feature = "mice-keyboard")]
+            tap_distance: 10.0,
+            #[cfg(feature = "mice-keyboard")]
+            typed: Vec::new(),
+            #[cfg(feature = "mice-keyboard")]
+            held_chars: Map::default(),
+            #[cfg(feature = "mice-keyboard")]
+            held_keys: Vec::new(),
+            #[cfg(feature = "mice-keyboard")]
+            ime_preedit: None,
+            #[cfg(feature = "mice-keyboard")]
+            touches: Map::default(),
+            #[cfg(feature = "mice-keyboard")]
+            swipes: Vec::new(),
+            #[cfg(feature = "mice-keyboard")]
+            swipe_distance: 50.0,
+            #[cfg(feature = "mice-keyboard")]
+            swipe_speed: 300.0,
+            recently_pressed:  None,
+            recent_inputs: Vec::new(),
+            active_input_kind: InputKind::default(),
+            rebinding: None,
+            swallowed: Vec::new(),
+            text_typed:        None,
+            bind_hash:  Map::default(),
+            action_val: Map::default(),
+            code_values: Map::default(),
+            integrating: Map::default(),
+            smoothing: Map::default(),
+            time: (Duration::ZERO, Duration::ZERO),
+            press_times: Map::default(),
+            press_counts: Map::default(),
+            press_order: (0, Map::default()),
+            bind_press_order: (0, Map::default()),
+            press_sources: (None, Map::default()),
+            turbo: Map::default(),
+            loop_count: 0,
+            chords: Map::default(),
+            combine_modes: Map::default(),
+            bind_groups: Map::default(),
+            bind_scales: Map::default(),
+            disabled_groups: Vec::new(),
+            bind_layers: Map::default(),
+            disabled_layers: Vec::new(),
+            min_binds: Map::default(),
+            action_sensitivity: Map::default(),
+            sticky: Vec::new(),
+            start_values: Map::default(),
+            changes: None,
+            peaks: Map::default(),
+            history: VecDeque::new(),
+            history_capacity: 0,
+            clamp_to_unit: false,
+            #[cfg(feature = "mice-keyboard")]
+            focus: true,
+            #[cfg(feature = "mice-keyboard")]
+            focus_gained: None,
+            #[cfg(feature = "mice-keyboard")]
+            focus_mouse_delay: Duration::ZERO,
+            #[cfg(feature = "gamepad")]
+            gamepads: Map::default(),
+            #[cfg(feature = "gamepad")]
+            sticks: Map::default(),
+            #[cfg(feature = "gamepad")]
+            stick_deadzone: 0.1,
+            #[cfg(feature = "gamepad")]
+            disabled_gamepads: Vec::new(),
+            #[cfg(feature = "gamepad")]
+            gamepad_deadzones: Map::default(),
+            #[cfg(feature = "gamepad")]
+            button_values: Map::default(),
+            #[cfg(feature = "gamepad")]
+            rumbles: Vec::new(),
+            #[cfg(feature = "gamepad")]
+            gamepad_event_time: None,
+            #[cfg(feature = "gamepad")]
+            button_remap: Map::default(),
+        }
+    }
+}
+impl<F: Hash + Copy + Eq> InputMap<F> {
+    /// Creates a new input system. Takes the action and a list of its associated binds. An action
+    /// will count as being pressed if any of the binds are pressed. A bind is a list of
+    /// `InputCode`s that need to all be pressed for the bind to count as being pressed.
+    ///
+    /// It's recommended to use the `input_map!` macro to reduce boilerplate
+    /// and increase readability.
+    /// ```
+    /// use Action::*;
+    /// use winit_input_map::*;
+    /// use winit::keyboard::KeyCode;
+    /// #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// enum Action {
+    ///     Forward,
+    ///     Back,
+    ///     Pos,
+    ///     Neg
+    /// }
+    /// // doesnt have to be the same ordered as the enum.
+    /// let input = InputMap::new(&vec![
+    ///     (Forward, vec![ vec![KeyCode::KeyW.into()] ]),
+    ///     (Pos,     vec![ vec![KeyCode::KeyA.into()] ]),
+    ///     (Back,    vec![ vec![KeyCode::KeyS.into()] ]),
+    ///     (Neg,     vec![ vec![KeyCode::KeyD.into()] ])
+    /// ]);
+    /// ```
+    pub fn new(binds: &Binds<F>) -> Self {
+        let mut result = Self::default();
+        result.add_binds(binds);
+        result
+    }
+    /// Takes binds and adds them to the currently existing map. The `binds!()` macro will help
+    /// reduce the boiler_plate of this function.
+    pub fn add_binds(&mut self, binds: &Binds<F>) {
+        for (action, binds) in binds {
+            for bind in binds {
+                let sub_values = &mut self.action_val.entry(*action).or_default().3;
+                // actions may already have binds so new ones go after them
+                let bind_i = sub_values.len();
+                sub_values.push((0.0, vec![0.0; bind.len()]));
+                for (code_i, code) in bind.iter().enumerate() {
+                    self.bind_hash.entry(*code).or_default().push((*action, bind_i, code_i));
+                }
+            }
+        }
+        self.action_val.shrink_to_fit();
+        self.bind_hash.shrink_to_fit();
+    }
+    /// Replaces a bind of an action, by its index in the actions list of binds, with the input
+    /// codes. If the index is the number of binds the action has its added as a new bind instead.
+    pub fn set_bind(&mut self, action: F, bind: usize, codes: &[InputCode]) {
+        let was_pressing = self.pressing(action);
+        let Some((_, _, _, sub_values)) = self.action_val.get_mut(&action) else { return };
+        if bind > sub_values.len() { return }
+        if bind == sub_values.len() { sub_values.push((0.0, vec![])) }
+        sub_values[bind] = (0.0, vec![0.0; codes.len()]);
+
+        for binds in self.bind_hash.values_mut() { binds.retain(|&(a, b, _)| a != action || b != bind) }
+        self.bind_hash.retain(|_, binds| !binds.is_empty());
+        for (code_i, code) in codes.iter().enumerate() {
+            self.bind_hash.entry(*code).or_default().push((action, bind, code_i));
+        }
+        if self.chords.contains_key(&(action, bind)) { self.chords.insert((action, bind), (false, vec![None; codes.len()])); }
+        // codes that are already held are put into the bind
+        for code in codes {
+            let held = self.code_values.get(code).copied().unwrap_or(0.0);
+            self.modify_single_val(*code, |_| held);
+        }
+        let mode = self.combine_modes.get(&action).copied().unwrap_or_default();
+        if let Some((val, _, _, sub_values)) = self.action_val.get_mut(&action) { *val = combine(sub_values, mode) }
+
+        let now_pressing = self.pressing(action);
+        let Some((_, pressed, released, _)) = self.action_val.get_mut(&action) else { return };
+        if now_pressing && !was_pressing { *pressed = true }
+        if !now_pressing && was_pressing { *released = true }
+    }
+    /// Removes every bind of the action along with its value, leaving the binds of other actions
+    /// alone even if they share input codes. Settings like `set_min_binds` are kept.
+    pub fn remove_action(&mut self, action: F) {
+        for binds in self.bind_hash.values_mut() { binds.retain(|&(a, _, _)| a != action) }
+        self.bind_hash.retain(|_, binds| !binds.is_empty());
+        self.action_val.remove(&action);
+        self.chords.retain(|&(a, _), _| a != action);
+        self.bind_groups.retain(|&(a, _), _| a != action);
+        self.bind_layers.retain(|&(a, _), _| a != action);
+        self.bind_scales.retain(|&(a, _), _| a != action);
+        self.bind_press_order.1.retain(|&(a, _), _| a != action);
+        if self.rebinding.as_ref().is_some_and(|r| r.0 == action) { self.rebinding = None }
+    }
+    /// Replaces all of the actions binds, useful for changing the controls of one action at
+    /// runtime. Codes that are already held are put into the new binds and the action is
+    /// `pressed` or `released` if that changes weather its pressed.
+    pub fn rebind_action(&mut self, action: F, binds: Vec<Vec<InputCode>>) {
+        let was_pressing = vec![(action, self.pressing(action))];
+        self.remove_action(action);
+        let codes: Vec<_> = binds.iter().flatten().copied().collect();
+        self.add_binds(&vec![(action, binds)]);
+        for code in codes {
+            let held = self.code_values.get(&code).copied().unwrap_or(0.0);
+            self.modify_single_val(code, |_| held);
+        }
+        self.update_pressing(was_pressing);
+    }
+    /// Makes the next input pressed, other than mouse movement, replace a bind of the action, by
+    /// its index in the actions list of binds. The input is bound for any device. Pressing one of
+    /// the `cancel` inputs stops without changing anything and pressing one of the `clear` inputs
+    /// removes the binds input codes. See `rebinding` to check if its still waiting for an input.
+    /// The input that finishes the rebind, whether its bound, cancels or clears, doesn't press
+    /// anything until its let go, so escape can cancel without also pausing the game.
+    /// ```ignore
+    /// if input.pressed(ClickedJumpSlot) {
+    ///     input.begin_rebind(Jump, 0, &[Escape.into(), East.into()], &[Delete.into()])
+    /// }
+    /// ```
+    pub fn begin_rebind(&mut self, action: F, bind: usize, cancel: &[InputCode], clear: &[InputCode]) {
+        self.rebinding = Some((action, bind, cancel.to_vec(), clear.to_vec()));
+    }
+    /// The action and bind waiting to be replaced by the next input pressed, see `begin_rebind`.
+    pub fn rebinding(&self) -> Option<(F, usize)> {
+        self.rebinding.as_ref().map(|r| (r.0, r.1))
+    }
+    /// Same as `add_binds` but doesn't add anything if any of the binds are the same as one the
+    /// action already has, or is given twice, returning them instead. Duplicate binds stack
+    /// their value so they're rarely wanted, useful when merging binds from config files.
+    pub fn try_add_binds(&mut self, binds: &Binds<F>) -> Result<(), Binds<F>> {
+        let same = |a: &Vec<InputCode>, b: &Vec<InputCode>| a.len() == b.len()
+            && a.iter().all(|c| b.contains(c)) && b.iter().all(|c| a.contains(c));
+        let existing = self.get_binds();
+        let mut checked: Binds<F> = Vec::new();
+        let mut duplicates: Binds<F> = Vec::new();
+        for (action, binds) in binds {
+            for bind in binds {
+                let bound = existing.iter().chain(checked.iter())
+                    .any(|(a, b)| a == action && b.iter().any(|b| same(b, bind)));
+                let list = if bound { &mut duplicates } else { &mut checked };
+                match list.iter_mut().find(|(a, _)| a == action) {
+                    Some((_, b)) => b.push(bind.clone()),
+                    None => list.push((*action, vec![bind.clone()]))
+                }
+            }
+        }
+        if !duplicates.is_empty() { return Err(duplicates) }
+        self.add_binds(binds);
+        Ok(())
+    }
+    /// Removes all binds and then adds the inputed binds. The `binds!()` macro will help
+    /// reduce the boiler_plate of this function.
+    pub fn set_binds(&mut self, binds: &Binds<F>) {
+        self.bind_hash.clear();
+        self.action_val.clear();
+        self.chords.clear();
+        self.bind_groups.clear();
+        self.bind_layers.clear();
+        self.bind_scales.clear();
+        self.bind_press_order.1.clear();
+        self.add_binds(binds);
+    }
+    /// Makes a bind of an action, by its index in the actions list of binds, only count as pressed
+    /// if all of its input codes are pressed in the same loop. Normal binds are pressed whenever
+    /// all their input codes are held no matter when they were pressed, so holding `L1` and then
+    /// pressing `R1` a second later would count. Simultaneous binds are useful for combos like
+    /// pressing `L1` and `R1` together.
+    pub fn set_simultaneous(&mut self, action: F, bind: usize, simultaneous: bool) {
+        let Some(codes) = self.action_val.get(&action).and_then(|v| v.3.get(bind)) else { return };
+        if simultaneous { self.chords.insert((action, bind), (false, vec![None; codes.1.len()])); }
+        else { self.chords.remove(&(action, bind)); }
+    }
+    /// Sets how the values of the actions binds are combined. By default they're added together,
+    /// so a key and a half pressed trigger give 1.5, but with `CombineMode::Max` they give 1.
+    pub fn set_combine_mode(&mut self, action: F, mode: CombineMode) {
+        if mode == CombineMode::Sum { self.combine_modes.remove(&action); }
+        else { self.combine_modes.insert(action, mode); }
+        self.refresh_binds(|a, _| a == action);
+    }
+    /// Puts a bind of an action, by its index in the actions list of binds, in a group so it can be
+    /// turned on and off with the rest of the group using `set_group_enabled`. Useful for things
+    /// like tools in an editor that each have their own controls.
+    pub fn set_bind_group(&mut self, action: F, bind: usize, group: u32) {
+        self.bind_groups.insert((action, bind), group);
+        self.refresh_binds(|a, b| a == action && b == bind);
+    }
+    /// Multiplies the value of a bind of an action, by its index in the actions list of binds. Useful
+    /// for changing the sensitivity of one bind, like doubling it for a gamepad stick but not the
+    /// mouse. Negative scales invert the bind so it takes away from the actions value, which
+    /// flips `axis` and `dir`, but an action with a negative value never counts as pressed.
+    /// ```ignore
+    /// // inverted camera y for the mouse only
+    /// input.set_bind_scale(LookUp, 0, -1.0);
+    /// input.set_bind_scale(LookDown, 0, -1.0);
+    /// ```
+    pub fn set_bind_scale(&mut self, action: F, bind: usize, scale: f32) {
+        if scale == 1.0 { self.bind_scales.remove(&(action, bind)); }
+        else { self.bind_scales.insert((action, bind), scale); }
+        self.refresh_binds(|a, b| a == action && b == bind);
+    }
+    /// Adds the binds to the named layer, a set of binds that can be turned on and off together
+    /// with `set_layer_enabled`. Useful for giving gameplay, menus and dialog their own binds.
+    /// Binds added any other way are in `DEFAULT_LAYER`, and an action bound in several turned on
+    /// layers combines the values of its binds like normal.
+    /// ```ignore
+    /// input.add_layer_binds("menu", &binds!((Confirm, Enter, South), (Back, Escape, East)));
+    /// input.set_layer_enabled("menu", false);
+    /// ```
+    pub fn add_layer_binds(&mut self, layer: &'static str, binds: &Binds<F>) {
+        // an action can be given more than once so the index of its next bind is kept as it goes
+        let mut next = Map::<F, usize>::default();
+        for (action, binds) in binds {
+            let start = next.entry(*action)
+                .or_insert_with(|| self.action_val.get(action).map(|v| v.3.len()).unwrap_or(0));
+            if layer != DEFAULT_LAYER {
+                for bind in *start..*start + binds.len() { self.bind_layers.insert((*action, bind), layer); }
+            }
+            *start += binds.len();
+        }
+        self.add_binds(binds);
+    }
+    /// Checks if the layer is turned on, see `set_layer_enabled`. Layers are on by default.
+    pub fn layer_enabled(&self, layer: &str) -> bool {
+        !self.disabled_layers.contains(&layer)
+    }
+    /// Turns every bind in the layer on or off. Turned off binds dont add to their actions value
+    /// but are kept so they work again when turned back on.
+    pub fn set_layer_enabled(&mut self, layer: &'static str, enabled: bool) {
+        self.disabled_layers.retain(|&l| l != layer);
+        if !enabled { self.disabled_layers.push(layer) }
+        let layers = self.bind_layers.clone();
+        self.refresh_binds(|action, bind| layers.get(&(action, bind)).copied().unwrap_or(DEFAULT_LAYER) == layer);
+    }
+    /// Returns the binds in the layer, the same way `get_binds` does for every layer.
+    pub fn layer_binds(&self, layer: &str) -> Binds<F> {
+        self.get_binds().into_iter().filter_map(|(action, binds)| {
+            let binds: Vec<_> = binds.into_iter().enumerate()
+                .filter(|(bind, _)| self.bind_layers.get(&(action, *bind)).copied().unwrap_or(DEFAULT_LAYER) == layer)
+                .map(|(_, bind)| bind).collect();
+            (!binds.is_empty()).then_some((action, binds))
+        }).collect()
+    }
+    /// Checks if the group is turned on, see `set_group_enabled`. Groups are on by default.
+    pub fn group_enabled(&self, group: u32) -> bool {
+        !self.disabled_groups.contains(&group)
+    }
+    /// Turns every bind in the group on or off. Turned off binds dont add to their actions value
+    /// but are kept so they work again when turned back on.
+    pub fn set_group_enabled(&mut self, group: u32, enabled: bool) {
+        self.disabled_groups.retain(|&g| g != group);
+        if !enabled { self.disabled_groups.push(group) }
+        let binds: Vec<_> = self.bind_groups.iter().filter(|(_, &g)| g == group).map(|(&b, _)| b).collect();
+        self.refresh_binds(|action, bind| binds.contains(&(action, bind)));
+    }
+    /// works out the value of the binds again, for when something other than their input changes
+    fn refresh_binds(&mut self, binds: impl Fn(F, usize) -> bool) {
+        let codes: Vec<_> = self.bind_hash.iter()
+            .filter(|(_, b)| b.iter().any(|&(action, bind, _)| binds(action, bind)))
+            .map(|(&code, _)| code).collect();
+        for code in codes { self.modify_single_val(code, |v| v) }
+    }
+    /// Returns the current binds of the InputMap, may not be in the same order as the inputed
+    /// binds.
+    pub fn get_binds(&self) -> Binds<F> {
+        let mut results = Vec::new();
+
+        for (input_code,  binds) in self.bind_hash.iter() {
+            for (action, binds_i, bind_i) in binds.iter() {
+                let action_i = if let Some(result) = results.iter().position(|(a, _)| a == action) { result }
+                else { results.push((*action, vec![])); results.len() - 1 };
+
+                let len = results[action_i].1.len();
+                if *binds_i >= len { results[action_i].1.append(&mut vec![vec![]; binds_i - len + 1]); }
+
+                let vec = &mut results[action_i].1[*binds_i];
+
+                let len = vec.len();
+                if *bind_i >= len { vec.append(&mut vec![*input_code; bind_i - len + 1]); }
+                vec[*bind_i] = *input_code;
+            }
+        }
+        results
+    }
+    /// Returns each bind that has all of the held input codes and more, along with its action. Useful
+    /// for showing what can be pressed next while holding a modifier, like listing every `Ctrl`
+    /// shortcut while `Ctrl` is held.
+    pub fn binds_with_prefix(&self, held: &[InputCode]) -> Vec<(F, Vec<InputCode>)> {
+        self.get_binds().into_iter().flat_map(|(action, binds)| binds.into_iter().map(move |b| (action, b)))
+            .filter(|(_, bind)| bind.len() > held.len() && held.iter().all(|code| bind.contains(code)))
+            .collect()
+    }
+    /// Returns every action the input code is bound to, each only once. A code with a specific
+    /// device or gamepad also matches binds for any device, since its input would trigger them,
+    /// but a code for any device doesn't match binds for specific devices. Useful for showing
+    /// conflicts when rebinding.
+    pub fn actions_for(&self, code: InputCode) -> Vec<F> {
+        let mut actions = Vec::new();
+        let any = (!code.is_any()).then(|| code.set_any());
+        for binds in [Some(code), any].into_iter().flatten().filter_map(|c| self.bind_hash.get(&c)) {
+            for &(action, _, _) in binds {
+                if !actions.contains(&action) { actions.push(action) }
+            }
+        }
+        actions
+    }
+    /// Updates the input map using a winit event. Make sure to call `input.init()` when your done with
+    /// the input this loop.
+    /// ```no_run
+    /// use winit::{event::*, window::WindowAttributes, event_loop::EventLoop};
+    /// use winit_input_map::*;
+    ///
+    /// let mut event_loop = EventLoop::new().unwrap();
+    /// event_loop.set_control_flow(winit::event_loop::ControlFlow::Poll);
+    /// let _window = event_loop.create_window(WindowAttributes::default()).unwrap();
+    ///
+    /// let mut input = input_map!();
+    ///
+    /// event_loop.run(|event, target|{
+    ///     input.update_with_winit(&event);
+    ///     match &event{
+    ///         Event::WindowEvent { event: WindowEvent::CloseRequested, .. } => target.exit(),
+    ///         Event::AboutToWait => input.init(),
+    ///         _ => ()
+    ///     }
+    /// });
+    /// ```
+    #[cfg(feature = "mice-keyboard")]
+    #[deprecated = "use `update_with_window_event` and `update_with_device_event`"]
+    pub fn update_with_winit(&mut self, event: &Event<()>) {
+        match event {
+            Event::WindowEvent { event, .. } => self.update_with_window_event(event),
+            Event::DeviceEvent { event, device_id, .. } => self.update_with_device_event(*device_id, event),
+            _ => ()
+        }
+    }
+    #[cfg(feature = "mice-keyboard")]
+    pub fn update_with_device_event(&mut self, id: DeviceId, event: &DeviceEvent) {
+        if !self.accept_input || !self.focus { return }
+        use base_input_codes::*;
+        match event {
+            DeviceEvent::MouseMotion { delta } => {
+                self.see_device(id);
+                if let Some((loop_count, time)) = self.focus_gained {
+                    let waiting = self.time.0.saturating_sub(time) < self.focus_mouse_delay;
+                    if loop_count == self.loop_count || waiting { return }
+                }
+                let x_sign = if self.invert_mouse_x { -1.0 } else { 1.0 };
+                let y_sign = if self.invert_mouse_y { -1.0 } else { 1.0 };
+                let distance = (delta.0 * delta.0 + delta.1 * delta.1).sqrt() as f32;
+                let acceleration = (1.0 + self.mouse_acceleration * distance).min(self.mouse_acceleration_max.max(1.0));
+                let x = delta.0 as f32 * self.mouse_scale * x_sign * acceleration;
+                let y = delta.1 as f32 * self.mouse_scale * y_sign * acceleration;
+                self.mouse_delta = (self.mouse_delta.0 + x, self.mouse_delta.1 + y);
+                self.modify_val(MouseMoveRight.with_id(id), |v| v + x.max(0.0));
+                self.modify_val(MouseMoveLeft .with_id(id), |v| v - x.min(0.0));
+                self.modify_val(MouseMoveDown .with_id(id), |v| v + y.max(0.0));
+                self.modify_val(MouseMoveUp   .with_id(id), |v| v - y.min(0.0));
+                self.modify_val(MouseMove     .with_id(id), |v| v + (x*x + y*y).sqrt());
+                if self.mouse_as_rate {
+                    for (input, moved) in [(MouseMoveRight, x.max(0.0)), (MouseMoveLeft, -x.min(0.0)),
+                    (MouseMoveDown, y.max(0.0)), (MouseMoveUp, -y.min(0.0)), (MouseMove, (x*x + y*y).sqrt())] {
+                        *self.mouse_moved.0.entry(input.with_id(id)).or_default() += moved;
+                        *self.mouse_moved.0.entry(input.into()).or_default() += moved;
+                    }
+                }
+            },
+            DeviceEvent::MouseWheel { delta } => self.update_scroll(*delta, id),
+             _ => (),
+        }
+    }
+    #[cfg(feature = "mice-keyboard")]
+    pub fn update_with_window_event(&mut self, event: &WindowEvent) {
+        // the window state is kept up to date while input is turned off, and keys and buttons only
+        // use releases then so nothing is stuck held once its back on
+        let used_while_off = match event {
+            WindowEvent::TouchpadPressure { pressure, .. } => *pressure == 0.0,
+            WindowEvent::KeyboardInput { .. } | WindowEvent::MouseInput { .. }
+            | WindowEvent::Resized(_) | WindowEvent::ScaleFactorChanged { .. } | WindowEvent::Focused(_)
+            | WindowEvent::CursorEntered { .. } | WindowEvent::CursorLeft { .. } => true,
+            _ => false
+        };
+        if !self.accept_input && !used_while_off { return }
+        match event {
+            WindowEvent::CursorMoved { position, device_id } => {
+                self.see_device(*device_id);
+                self.cursor_in_window = true;
+                self.update_mouse(*position)
+            },
+            WindowEvent::CursorEntered { .. } => self.cursor_in_window = true,
+            WindowEvent::CursorLeft    { .. } => self.cursor_in_window = false,
+            WindowEvent::MouseWheel { delta, device_id, .. } => self.update_scroll(*delta, *device_id),
+            WindowEvent::MouseInput { state, button, device_id } => self.update_buttons(state, *device_id, *button),
+            WindowEvent::KeyboardInput { event, device_id, .. } => self.update_keys(*device_id, event),
+            WindowEvent::Touch(touch) => self.update_touch(touch),
+            WindowEvent::TouchpadPressure { device_id, pressure, .. } => {
+                self.see_device(*device_id);
+                self.update_val(DeviceInput::TouchpadPressure.with_id(*device_id), *pressure);
+            },
+            WindowEvent::Resized(size) => self.set_window_size((size.width as f32, size.height as f32)),
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => self.set_scale_factor(*scale_factor as f32),
+            WindowEvent::Ime(ime) => self.update_ime(ime),
+            WindowEvent::Focused(focus) => self.set_focus(*focus),
+            _ => ()
+        }
+    }
+    /// Turns using events from winit and gilrs on or off, same as setting `accept_input`. While
+    /// turned off events are still taken from gilrs so they dont queue up, but nothing is pressed,
+    /// `recently_pressed` or typed. Releases still go through so nothing is stuck held once its
+    /// turned back on. Useful for freezing input during cutscenes without losing the binds.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.accept_input = enabled;
+    }
+    /// Checks if events from winit and gilrs are being used, see `set_enabled`.
+    pub fn enabled(&self) -> bool {
+        self.accept_input
+    }
+    /// The kind of device the player last pressed something on, keyboard and mouse or gamepad.
+    /// Useful for switching between keyboard and gamepad button prompts.
+    pub fn active_input_kind(&self) -> InputKind {
+        self.active_input_kind
+    }
+    /// Takes every input code that was pressed this loop, in the order they were pressed, even if
+    /// they aren't bound to anything. Unlike `recently_pressed` no inputs are missed when several
+    /// are pressed in the same loop, useful for rebinding.
+    pub fn take_recent_inputs(&mut self) -> Vec<InputCode> {
+        std::mem::take(&mut self.recent_inputs)
+    }
+    /// Updates the input map with each window event in order, same as calling
+    /// `update_with_window_event` for each of them.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn update_with_window_events(&mut self, events: &[WindowEvent]) {
+        events.iter().for_each(|event| self.update_with_window_event(event));
+    }
+    /// Updates the input map with each device event in order, same as calling
+    /// `update_with_device_event` for each of them.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn update_with_device_events(&mut self, events: &[(DeviceId, DeviceEvent)]) {
+        events.iter().for_each(|(id, event)| self.update_with_device_event(*id, event));
+    }
+    #[cfg(feature = "gamepad")]
+    pub fn update_with_gilrs(&mut self, gilrs: &mut gilrs::Gilrs) {
+        let now = std::time::Instant::now();
+        self.rumbles.retain(|(_, end)| *end > now);
+        while let Some(ev) = gilrs.next_event() {
+            if let gilrs::EventType::Disconnected = ev.event { self.gamepads.remove(&ev.id); }
+            else { self.gamepads.entry(ev.id).or_insert_with(|| {
+                let gamepad = gilrs.gamepad(ev.id);
+                (gamepad.into(), gamepad.name().to_string())
+            }); }
+
+            // without a window theres nothing to lose focus so its always focused
+            #[cfg(feature = "mice-keyboard")]
+            let focus = self.focus;
+            #[cfg(not(feature = "mice-keyboard"))]
+            let focus = true;
+            if focus { self.update_gamepad(ev); }
+        }
+    }
+    /// Advances everything that depends on time, like integrating actions. Call it once per loop
+    /// after the other update functions and before using the input.
+    pub fn update_with_dt(&mut self, dt: Duration) {
+        self.time = (self.time.0 + dt, dt);
+        let dt = dt.as_secs_f32();
+        #[cfg(feature = "mice-keyboard")]
+        if self.mouse_as_rate {
+            // the distance moved is kept separately so calling this more than once a loop gives the
+            // rate over the whole loop instead of dividing again
+            self.mouse_moved.1 += dt;
+            let time = self.mouse_moved.1;
+            let moved = self.mouse_moved.0.iter().filter(|(code, _)| !matches!(code,
+                InputCode::Device { input, .. } if self.accumulating.contains(input)
+            )).map(|(&code, &moved)| (code, moved)).collect::<Vec<_>>();
+            if time > 0.0 { for (code, moved) in moved { self.modify_single_val(code, |_| moved / time) } }
+        }
+        for (action, (rate, smoothed)) in self.smoothing.iter_mut() {
+            let target = self.action_val.get(action).map(|v| v.0 * self.global_scale).unwrap_or(0.0);
+            *smoothed += (target - *smoothed).clamp(-*rate * dt, *rate * dt);
+        }
+        for (action, (rate, filled)) in self.integrating.iter_mut() {
+            let value = self.action_val.get(action).map(|v| v.0 * self.global_scale).unwrap_or(0.0);
+            let sens = self.action_sensitivity.get(action).copied().unwrap_or(self.press_sensitivity);
+            if value >= sens { *filled += value * *rate * dt }
+        }
+        for (action, (rate, timer)) in self.turbo.iter_mut() {
+            let Some(val) = self.action_val.get_mut(action) else { continue };
+            let sens = self.action_sensitivity.get(action).copied().unwrap_or(self.press_sensitivity);
+            // the timer restarts when the action is first pressed
+            if val.0 * self.global_scale < sens || val.1 { *timer = 0.0; continue }
+
+            *timer += dt;
+            let interval = 1.0 / *rate;
+            if *timer >= interval {
+                *timer %= interval;
+                val.1 = true;
+            }
+        }
+    }
+    /// Makes the input map ready to recieve new events.
+    pub fn init(&mut self) {
+        #[cfg(feature = "mice-keyboard")]
+        {
+            use base_input_codes::*;
+            for i in [MouseMove, MouseMoveLeft, MouseMoveRight,
+            MouseMoveUp, MouseMoveDown, MouseScrollUp,
+            MouseScrollDown, MouseScrollLeft, 
+            MouseScrollRight] {
+                if !self.accumulating.contains(&i) { self.update_val(i.into(), 0.0) }
+            }
+            // mouse inputs from specific devices are reset too so they dont build up
+            let mouse = self.code_values.keys().filter(|code| matches!(code, InputCode::Device {
+                input: input @ (MouseMove | MouseMoveLeft | MouseMoveRight | MouseMoveUp | MouseMoveDown
+                | MouseScrollUp | MouseScrollDown | MouseScrollLeft | MouseScrollRight), ..
+            } if !self.accumulating.contains(input))).copied().collect::<Vec<_>>();
+            for code in mouse { self.modify_single_val(code, |_| 0.0) }
+            self.scroll_delta = (0.0, 0.0);
+            self.mouse_delta = (0.0, 0.0);
+            self.mouse_moved.0.clear();
+            self.mouse_moved.1 = 0.0;
+        }
+        if self.history_capacity > 0 {
+            let pressed = self.action_val.iter().filter(|(_, v)| v.1).map(|(a, _)| *a).collect();
+            self.history.push_front(pressed);
+            self.history.truncate(self.history_capacity);
+        }
+        for (action, (_, p, r, _)) in self.action_val.iter_mut() {
+            if !self.sticky.contains(action) { *p = false }
+            *r = false;
+        }
+        self.start_values.clear();
+        self.press_counts.clear();
+        self.loop_count += 1;
+        self.recently_pressed = None;
+        self.recent_inputs.clear();
+        self.text_typed = None;
+        #[cfg(feature = "mice-keyboard")]
+        {
+            self.swipes.clear();
+            self.typed.clear();
+        }
+    }
+    #[cfg(feature = "mice-keyboard")]
+    fn update_scroll(&mut self, delta: MouseScrollDelta, id: DeviceId) {
+        use base_input_codes::*;
+        let (x, y) = match delta {
+        MouseScrollDelta::LineDelta(x, y) => (x, y),
+            MouseScrollDelta::PixelDelta(PhysicalPosition { x, y }) => (x as f32, y as f32)
+        };
+        // winit gives scrolling left as positive x, which is flipped so right is positive like
+        // `MouseScrollRight`
+        self.scroll_delta = (self.scroll_delta.0 - x, self.scroll_delta.1 + y);
+        let (x, y) = (x * self.scroll_scale, y * self.scroll_scale);
+        self.see_device(id);
+        
+        self.modify_val(MouseScrollUp.with_id(id),    |v| v + y.max(0.0));
+        self.modify_val(MouseScrollDown.with_id(id),  |v| v - y.min(0.0));
+        self.modify_val(MouseScrollLeft.with_id(id),  |v| v + x.max(0.0));
+        self.modify_val(MouseScrollRight.with_id(id), |v| v - x.min(0.0));
+    }
+    #[cfg(feature = "mice-keyboard")]
+    fn update_mouse(&mut self, position: PhysicalPosition<f64>) {
+        let (x, y) = (position.x as f32, position.y as f32);
+        self.mouse_pos = (x, y);
+        let held: Vec<F> = self.press_positions.keys().copied().filter(|&a| self.pressing(a)).collect();
+        for action in held {
+            let Some(((start_x, start_y), furthest)) = self.press_positions.get_mut(&action) else { continue };
+            *furthest = furthest.max(((x - *start_x).powi(2) + (y - *start_y).powi(2)).sqrt());
+        }
+    }
+    #[cfg(feature = "mice-keyboard")]
+    fn update_touch(&mut self, touch: &Touch) {
+        let (x, y) = (touch.location.x as f32, touch.location.y as f32);
+        match touch.phase {
+            TouchPhase::Started => { self.touches.insert(touch.id, ((x, y), self.time.0)); },
+            TouchPhase::Moved => (),
+            TouchPhase::Cancelled => { self.touches.remove(&touch.id); },
+            TouchPhase::Ended => {
+                let Some(((start_x, start_y), start_time)) = self.touches.remove(&touch.id) else { return };
+                let (dx, dy) = (x - start_x, y - start_y);
+
+                let distance = (dx*dx + dy*dy).sqrt();
+                let time = (self.time.0 - start_time).as_secs_f32();
+                if distance < self.swipe_distance || distance < self.swipe_speed * time { return }
+
+                self.swipes.push(if dx.abs() > dy.abs() {
+                    if dx > 0.0 { SwipeDirection::Right } else { SwipeDirection::Left }
+                } else if dy > 0.0 { SwipeDirection::Down } else { SwipeDirection::Up });
+            }
+        }
+    }
+    /// Returns the touch swipes that happened since the last call, in the order they happened.
+    /// A swipe is a touch that moves at least `swipe_distance` at `swipe_speed` or faster.
+    /// Swipes that aren't taken are cleared in `init`.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn take_swipes(&mut self) -> Vec<SwipeDirection> {
+        std::mem::take(&mut self.swipes)
+    }
+    /// Sets the size of the window in pixels, used for things like `mouse_pos_normalized`. This
+    /// is done automatically by `update_with_window_event` when the window is resized but should
+    /// be set when the window is created.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn set_window_size(&mut self, size: (f32, f32)) {
+        self.window_size = size;
+    }
+    /// The size of the window in pixels.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn window_size(&self) -> (f32, f32) {
+        self.window_size
+    }
+    /// Sets weather the window has focus, done automatically by `update_with_window_event`.
+    /// Losing focus resets every input and stops device and gamepad events from being used until
+    /// its focused again, so things like a camera dont move while the player is in another window.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn set_focus(&mut self, focus: bool) {
+        if focus && !self.focus { self.focus_gained = Some((self.loop_count, self.time.0)) }
+        self.focus = focus;
+        if focus { return }
+        for val in self.action_val.values_mut() {
+            val.3.iter_mut().for_each(|i| { i.0 = 0.0; i.1.iter_mut().for_each(|i| *i = 0.0) });
+            val.0 = 0.0;
+        }
+        self.held_keys.clear();
+        self.held_chars.clear();
+        self.swallowed.clear();
+        self.code_values.clear();
+        #[cfg(feature = "gamepad")]
+        {
+            self.sticks.clear();
+            self.button_values.clear();
+        }
+    }
+    /// Checks if the window has focus.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn focused(&self) -> bool {
+        self.focus
+    }
+    /// How far the mouse wheel scrolled this loop as a vector, with up and right being positive,
+    /// the same directions as `MouseScrollUp` and `MouseScrollRight`. Unlike the scroll input codes it isn't split into directions or multiplied by
+    /// `scroll_scale`, useful for custom zoom curves. Line and pixel scrolling are added together
+    /// as is, so their units depend on the platform.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn scroll_delta<V: Vec2Like>(&self) -> V {
+        V::new(self.scroll_delta.0, self.scroll_delta.1)
+    }
+    /// How far the mouse moved this loop as a vector, with right and down being positive. Uses the
+    /// same movement as the mouse move input codes, so `mouse_scale`, `mouse_acceleration` and
+    /// inverting are applied. Useful for first person cameras.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn mouse_delta<V: Vec2Like>(&self) -> V {
+        V::new(self.mouse_delta.0, self.mouse_delta.1)
+    }
+    /// Checks if the mouse moved this loop. Useful for showing the cursor again as soon as the
+    /// mouse is touched.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn mouse_moved(&self) -> bool {
+        self.code_value(DeviceInput::MouseMove.into()) > 0.0
+    }
+    /// The mouse or keyboard that last pressed the action, even if its bound to any device.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn last_device(&self, action: F) -> Option<DeviceId> {
+        match self.press_sources.1.get(&action) {
+            Some(InputCode::Device { id: SpecifyDevice::Id(id), .. }) => Some(*id),
+            _ => None
+        }
+    }
+    /// Makes a mouse movement or scroll input keep adding up instead of being reset every loop,
+    /// until its reset with `reset_accumulated`. Useful for things like zooming with the scroll
+    /// wheel.
+    /// ```ignore
+    /// input.set_accumulating(MouseScrollUp, true);
+    /// // ...
+    /// let zoom = input.value(ZoomIn);
+    /// input.reset_accumulated(MouseScrollUp);
+    /// ```
+    #[cfg(feature = "mice-keyboard")]
+    pub fn set_accumulating(&mut self, input: DeviceInput, accumulate: bool) {
+        self.accumulating.retain(|&i| i != input);
+        if accumulate { self.accumulating.push(input) }
+    }
+    /// Sets an input made to accumulate with `set_accumulating` back to 0 for every device.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn reset_accumulated(&mut self, input: DeviceInput) {
+        let codes = self.code_values.keys().filter(|code| matches!(code, InputCode::Device { input: i, .. } if *i == input))
+            .copied().collect::<Vec<_>>();
+        for code in codes { self.modify_single_val(code, |_| 0.0) }
+    }
+    /// Checks if the cursor is inside the window. Useful for things like hiding the HUD when the
+    /// mouse leaves.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn cursor_in_window(&self) -> bool {
+        self.cursor_in_window
+    }
+    /// The mouse position in physical pixels, with 0, 0 being the top left of the window.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn mouse_pos<V: Vec2Like>(&self) -> V {
+        V::new(self.mouse_pos.0, self.mouse_pos.1)
+    }
+    /// The mouse position from 0 to 1 across the window, with 0, 0 being the top left. Returns
+    /// 0, 0 if the window size isn't known.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn mouse_pos_normalized<V: Vec2Like>(&self) -> V {
+        let ((x, y), (w, h)) = (self.mouse_pos, self.window_size);
+        if w <= 0.0 || h <= 0.0 { return V::new(0.0, 0.0) }
+        V::new(x / w, y / h)
+    }
+    /// Sets the scale factor of the window, used by `mouse_pos_logical`. This is done
+    /// automatically by `update_with_window_event` when it changes but should be set to
+    /// `window.scale_factor()` when the window is created.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn set_scale_factor(&mut self, scale_factor: f32) {
+        self.scale_factor = scale_factor;
+    }
+    /// The scale factor of the window.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+    /// The mouse position in logical pixels instead of the physical pixels of `mouse_pos`. Useful
+    /// for UI on high DPI screens.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn mouse_pos_logical<V: Vec2Like>(&self) -> V {
+        let (x, y) = self.mouse_pos;
+        V::new(x / self.scale_factor, y / self.scale_factor)
+    }
+    #[cfg(feature = "mice-keyboard")]
+    fn see_device(&mut self, id: DeviceId) {
+        if !self.devices.contains(&id) { self.devices.push(id) }
+    }
+    /// Every mouse and keyboard that has sent input so far, in the order they first did. Useful
+    /// for letting players pick a specific device to bind to.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn known_devices(&self) -> Vec<DeviceId> {
+        self.devices.clone()
+    }
+    #[cfg(feature = "mice-keyboard")]
+    fn push_text(&mut self, new: &str, key: Option<winit::keyboard::PhysicalKey>) {
+        self.text_typed.get_or_insert_with(String::new).push_str(new);
+        self.typed.push((key, new.to_string()));
+    }
+    /// The text typed this loop without control characters, like tab, escape or backspace, that
+    /// some platforms include. Useful for chat boxes, `text_typed` still has the raw text.
+    pub fn printable_text(&self) -> Option<String> {
+        let text: String = self.text_typed.as_ref()?.chars().filter(|c| !c.is_control()).collect();
+        if text.is_empty() { None } else { Some(text) }
+    }
+    /// The text typed this loop without the text typed by keys bound to any of the inputed
+    /// actions that were just pressed. Useful for stopping the key that submits a chat box
+    /// from also being typed into it.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn text_excluding(&self, actions: &[F]) -> Option<String> {
+        let excluded = |key: &winit::keyboard::PhysicalKey, text: &str| {
+            self.bind_hash.iter().any(|(code, binds)| {
+                #[allow(irrefutable_let_patterns)]
+                let InputCode::Device { input, .. } = code else { return false };
+                let bound = match input {
+                    DeviceInput::Key(k) => k == key,
+                    DeviceInput::Char(c) => text.to_lowercase() == c.to_string(),
+                    _ => false
+                };
+                bound && binds.iter().any(|(a, _, _)| actions.contains(a) && self.pressed(*a))
+            })
+        };
+        let text: String = self.typed.iter()
+            .filter(|(key, text)| !key.as_ref().is_some_and(|key| excluded(key, text)))
+            .map(|(_, text)| text.as_str()).collect();
+        if text.is_empty() { None } else { Some(text) }
+    }
+    #[cfg(feature = "mice-keyboard")]
+    fn update_ime(&mut self, ime: &Ime) {
+        match ime {
+            Ime::Preedit(text, _) if text.is_empty() => self.ime_preedit = None,
+            Ime::Preedit(text, cursor) => self.ime_preedit = Some((text.clone(), *cursor)),
+            Ime::Commit(text) => {
+                self.ime_preedit = None;
+                self.push_text(text, None);
+            },
+            Ime::Enabled => (),
+            Ime::Disabled => self.ime_preedit = None
+        }
+    }
+    /// The text currently being composed with an IME and the byte range of its cursor, useful
+    /// for showing text thats being typed in languages like Chinese or Japanese. Once its
+    /// finished the text is added to `text_typed`. The cursor is the same as winit gives, see
+    /// `ime_cursor` for one thats always set.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn ime_preedit(&self) -> Option<(String, Option<(usize, usize)>)> {
+        self.ime_preedit.clone()
+    }
+    /// The byte range of the cursor in the text from `ime_preedit`. If the IME didn't give a
+    /// cursor its at the end of the text. Returns `None` when no text is being composed.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn ime_cursor(&self) -> Option<(usize, usize)> {
+        self.ime_preedit.as_ref().map(|(text, cursor)| cursor.unwrap_or((text.len(), text.len())))
+    }
+    #[cfg(feature = "mice-keyboard")]
+    fn update_keys(&mut self, id: DeviceId, event: &KeyEvent) {
+        let text = event.text.as_ref().map(|t| t.as_str());
+        self.update_key(id, event.physical_key, &event.logical_key, text, event.state.is_pressed());
+    }
+    /// `update_keys` split from the `KeyEvent`, since they can't be made outside of winit
+    #[cfg(feature = "mice-keyboard")]
+    fn update_key(
+        &mut self, id: DeviceId, physical_key: winit::keyboard::PhysicalKey,
+        logical_key: &winit::keyboard::Key, text: Option<&str>, pressed: bool
+    ) {
+        if !self.accept_input && pressed { return }
+        self.see_device(id);
+        let input_code: DeviceInput = physical_key.into();
+
+        if let Some(new) = text { self.push_text(new, Some(physical_key)) }
+
+        // updated first so the real key is the one thats `recently_pressed`
+        let key = (id, physical_key);
+        self.held_keys.retain(|k| *k != key);
+        if pressed { self.held_keys.push(key) }
+        let any_held = self.held_keys.iter().any(|(i, _)| *i == id);
+        self.modify_single_val(DeviceInput::AnyKey.with_id(id), |_| any_held.into());
+        let any_held = !self.held_keys.is_empty();
+        self.modify_single_val(DeviceInput::AnyKey.into(), |_| any_held.into());
+
+        self.update_val(input_code.with_id(id), pressed.into());
+
+        // the char is remembered so its released even if a modifier changed what the key types
+        let char = if pressed {
+            let winit::keyboard::Key::Character(text) = logical_key else { return };
+            let mut chars = text.chars().flat_map(char::to_lowercase);
+            let (Some(char), None) = (chars.next(), chars.next()) else { return };
+            self.held_chars.insert(physical_key, char);
+            char
+        } else {
+            let Some(char) = self.held_chars.remove(&physical_key) else { return };
+            char
+        };
+        self.update_val(DeviceInput::Char(char).with_id(id), pressed.into());
+    }
+    #[cfg(feature = "mice-keyboard")]
+    fn update_buttons(&mut self, state: &ElementState, id: DeviceId, button: MouseButton) {
+        if !self.accept_input && state.is_pressed() { return }
+        self.see_device(id);
+        let input_code: DeviceInput = button.into();
+        self.update_val(input_code.with_id(id), state.is_pressed().into());
+    }
+    /// finishes `begin_rebind` with a newly pressed input
+    #[cfg(any(feature = "mice-keyboard", feature = "gamepad"))]
+    fn capture_rebind(&mut self, input_code: InputCode) {
+        let rebinding = if input_code.is_mouse_move() { None } else { self.rebinding.take() };
+        if let Some((action, bind, cancel, clear)) = rebinding {
+            let is = |codes: &[InputCode]| codes.iter().any(|&c| c == input_code || c == input_code.set_any());
+            self.swallowed.push(input_code);
+            if is(&clear) { self.set_bind(action, bind, &[]) }
+            else if !is(&cancel) { self.set_bind(action, bind, &[input_code.set_any()]) }
+        }
+    }
+    /// updates provided input code
+    #[cfg(any(feature = "mice-keyboard", feature = "gamepad"))]
+    fn update_val(&mut self, input_code: InputCode, val: f32) {
+        self.modify_val(input_code, |_| val);
+    }
+    #[cfg(any(feature = "mice-keyboard", feature = "gamepad"))]
+    fn modify_val<FN: Fn(f32) -> f32>(&mut self, input_code: InputCode, f: FN) {
+        self.modify_single_val(input_code, &f);
+        self.modify_single_val(input_code.set_any(), f);
+    }
+    /// doesnt update both generic ids and specified ids, use `update_val` or `modify_val` for that
+    fn modify_single_val<FN: Fn(f32) -> f32>(&mut self, input_code: InputCode, f: FN) {
+        // broken devices can give NaN or infinity which would stop the action from ever changing
+        let f = |v| Some(f(v)).filter(|v| v.is_finite()).unwrap_or(0.0);
+        if !input_code.is_any() { self.press_sources.0 = Some(input_code) }
+        let old_raw = self.code_values.get(&input_code).copied().unwrap_or(0.0);
+        let raw = f(old_raw);
+        if raw == 0.0 { self.code_values.remove(&input_code); }
+        else { self.code_values.insert(input_code, raw); }
+
+        let sens = self.press_sensitivity;
+        if raw >= sens && old_raw < sens && !input_code.is_any() && !input_code.is_meta() {
+            self.recent_inputs.push(input_code);
+            self.active_input_kind = input_code.kind();
+            #[cfg(any(feature = "mice-keyboard", feature = "gamepad"))]
+            self.capture_rebind(input_code);
+        }
+        if let Some(i) = self.swallowed.iter().position(|&c| c == input_code || c.set_any() == input_code) {
+            if raw < sens && !input_code.is_any() { self.swallowed.swap_remove(i); }
+            return
+        }
+
+        let Some(bind_count) = self.bind_hash.get(&input_code).map(Vec::len) else {
+            if raw >= self.press_sensitivity && !input_code.is_any() { self.recently_pressed = Some(input_code) }
+            return;
+        };
+
+        for i in 0..bind_count {
+            let (action, index, sub_index) = self.bind_hash[&input_code][i];
+            let was_pressing = self.pressing_unclamped(action);
+            let sens = self.sensitivity(action);
+            let scale = self.global_scale;
+            let (curr_val, _, _, sub_values) = &mut self.action_val.get_mut(&action).unwrap();
+            let old_val = *curr_val;
+
+            // every bind gets the codes raw value so binds added while its held dont fall behind
+            let old_sub_sub_val = sub_values[index].1[sub_index];
+            let new_sub_sub_val = raw;
+            sub_values[index].1[sub_index] = new_sub_sub_val;
+
+            let mut new_sub_val = bind_value(&sub_values[index].1);
+
+            if let Some((active, pressed_at)) = self.chords.get_mut(&(action, index)) {
+                if new_sub_sub_val >= sens && old_sub_sub_val < sens { pressed_at[sub_index] = Some(self.loop_count) }
+
+                let codes = &sub_values[index].1;
+                if codes.iter().any(|&v| v < sens) { *active = false }
+                else if !*active { *active = pressed_at.iter().all(|&l| l == Some(self.loop_count)) }
+                new_sub_val = if *active { bind_value(codes) } else { 0.0 };
+            }
+            if let Some(bind_scale) = self.bind_scales.get(&(action, index)) { new_sub_val *= bind_scale }
+            let layer = self.bind_layers.get(&(action, index)).copied().unwrap_or(DEFAULT_LAYER);
+            if self.bind_groups.get(&(action, index)).is_some_and(|g| self.disabled_groups.contains(g))
+            || self.disabled_layers.contains(&layer) {
+                new_sub_val = 0.0;
+            }
+            if new_sub_val * scale >= sens && sub_values[index].0 * scale < sens {
+                self.bind_press_order.0 += 1;
+                self.bind_press_order.1.insert((action, index), self.bind_press_order.0);
+            }
+            sub_values[index].0 = new_sub_val;
+
+            *curr_val = combine(sub_values, self.combine_modes.get(&action).copied().unwrap_or_default());
+            self.action_changed(action, old_val, was_pressing, Some(input_code));
+        }
+    }
+    /// Checks if the action is pressing without `clamp_all_to_unit`, which could stop
+    /// sensitivities over 1 from ever being reached.
+    fn pressing_unclamped(&self, action: F) -> bool {
+        let value = self.action_val.get(&action).map(|v| v.0).unwrap_or(0.0);
+        value * self.global_scale >= self.sensitivity(action) && self.enough_binds(action)
+    }
+    /// Updates everything that follows from the value of an action changing, like `pressed`,
+    /// `released` and the change log. Called after the value is changed by either an input code
+    /// or `decode_frame`, which has no input code.
+    fn action_changed(&mut self, action: F, old_val: f32, was_pressing: bool, input_code: Option<InputCode>) {
+        let curr_val = self.action_val.get(&action).map(|v| v.0).unwrap_or(0.0);
+        self.start_values.entry(action).or_insert(old_val);
+        if let (Some(changes), true) = (&mut self.changes, old_val != curr_val) {
+            if let Some(change) = changes.iter_mut().find(|c| c.0 == action) { change.2 = curr_val }
+            else { changes.push((action, old_val, curr_val)) }
+        }
+
+        let now_pressing = self.pressing_unclamped(action);
+        // `recently_pressed` isn't tied to an action so it keeps using the global sensitivity
+        let globally_pressing = curr_val * self.global_scale >= self.press_sensitivity && self.enough_binds(action);
+        if let Some(code) = input_code.filter(|code| globally_pressing && !code.is_any()) {
+            self.recently_pressed = Some(code)
+        }
+
+        let Some((_, pressed, released, _)) = self.action_val.get_mut(&action) else { return };
+        if now_pressing && !was_pressing { *pressed = true }
+        if !now_pressing && was_pressing { *released = true }
+
+        if now_pressing && !was_pressing {
+            self.press_times.insert(action, self.time.0);
+            *self.press_counts.entry(action).or_default() += 1;
+            self.press_order.0 += 1;
+            self.press_order.1.insert(action, self.press_order.0);
+            if let (Some(source), Some(_)) = (self.press_sources.0, input_code) {
+                self.press_sources.1.insert(action, source);
+            }
+            #[cfg(feature = "mice-keyboard")]
+            self.press_positions.insert(action, (self.mouse_pos, 0.0));
+        }
+
+        let peak = self.peaks.entry(action).or_default();
+        if now_pressing && !was_pressing { *peak = curr_val }
+        else if now_pressing { *peak = peak.max(curr_val) }
+    }
+    #[cfg(feature = "gamepad")]
+    fn update_gamepad(&mut self, event: gilrs::Event) {
+        let gilrs::Event { id, event, time, .. } = event;
+        if self.disabled_gamepads.contains(&id) { return }
+        // only releases are used while input is turned off so nothing is stuck held once its back on
+        let release = match event {
+            gilrs::EventType::ButtonChanged(_, v, _) | gilrs::EventType::AxisChanged(_, v, _) => v == 0.0,
+            gilrs::EventType::Disconnected => true,
+            _ => false
+        };
+        if !self.accept_input && !release { return }
+        self.gamepad_event_time = Some(time);
+        use crate::input_code::{axis_pos, axis_neg};
+        use gilrs::ev::EventType;
+        match event {
+            EventType::ButtonChanged(b, v, _) => {
+                let v = if v.is_finite() { v } else { 0.0 };
+                let a = self.button_remap.get(&b).copied().unwrap_or_else(|| b.into());
+                self.button_values.insert((id, a), v);
+                // updated first so the real button is the one thats `recently_pressed`
+                let (this, all) = (self.max_button(Some(id)), self.max_button(None));
+                self.modify_single_val(GamepadInput::AnyButton.with_id(id), |_| this);
+                self.modify_single_val(GamepadInput::AnyButton.into(), |_| all);
+                self.update_val(a.with_id(id), v);
+            },
+            EventType::AxisChanged(b, v, _) => {
+                let v = if v.is_finite() { v } else { 0.0 };
+                let stick_i = match b {
+                    gilrs::Axis::LeftStickX  => Some(0), gilrs::Axis::LeftStickY  => Some(1),
+                    gilrs::Axis::RightStickX => Some(2), gilrs::Axis::RightStickY => Some(3),
+                    _ => None
+                };
+                if let Some(i) = stick_i {
+                    let stick = self.sticks.entry(id).or_default();
+                    stick[i] = v;
+                    // both axes of the stick are updated since the radial deadzone depends on them both
+                    let (x, y) = (stick[i / 2 * 2], stick[i / 2 * 2 + 1]);
+                    use GamepadInput::*;
+                    let codes = if i < 2 { [LeftStickRight, LeftStickLeft, LeftStickUp, LeftStickDown] }
+                    else { [RightStickRight, RightStickLeft, RightStickUp, RightStickDown] };
+                    self.update_stick(codes.map(|c| c.with_id(id)), x, y, self.gamepad_deadzone(id));
+                    return
+                }
+
+                let dir_pos = v.max(0.0);
+                let dir_neg = (-v).max(0.0);
+                let input_pos = axis_pos(b);
+                let input_neg = axis_neg(b);
+
+                self.update_val(input_pos.with_id(id), dir_pos);
+                self.update_val(input_neg.with_id(id), dir_neg);
+            },
+            EventType::Disconnected => self.reset_gamepad(id),
+            _ => ()
+        }
+    }
+    /// updates the right, left, up and down input codes of a stick with its deadzone applied
+    #[cfg(feature = "gamepad")]
+    fn update_stick(&mut self, codes: [InputCode; 4], x: f32, y: f32, deadzone: f32) {
+        let (x, y) = radial_deadzone(x, y, deadzone);
+        for (code, v) in codes.into_iter().zip([x, -x, y, -y]) { self.update_val(code, v.max(0.0)) }
+    }
+    #[cfg(feature = "gamepad")]
+    fn reset_gamepad(&mut self, id: gilrs::GamepadId) {
+        self.sticks.remove(&id);
+        self.button_values.retain(|(i, _), _| *i != id);
+
+        use GamepadInput::*;
+        for i in [LeftStickLeft, LeftStickRight, LeftStickUp, LeftStickDown, LeftStickPress,
+         RightStickLeft, RightStickRight, RightStickUp, RightStickDown,
+         RightStickPress, DPadLeft, DPadRight, DPadUp, DPadDown, LeftZ, RightZ,
+         South, East, North, West, LeftBumper, LeftTrigger, RightBumper,
+         RightTrigger,  Select, Start, Mode, GyroPitchUp, GyroPitchDown, GyroYawLeft,
+         GyroYawRight, GyroRollLeft, GyroRollRight, AnyButton, Other] {
+            self.modify_single_val(i.with_id(id), |_| 0.0);
+            // other gamepads can still be pressing it
+            let any: InputCode = i.into();
+            let held = self.code_values.iter().filter(|(code, _)| !code.is_any() && code.set_any() == any)
+                .fold(0.0, |max: f32, (_, &v)| max.max(v));
+            self.modify_single_val(any, |_| held);
+        }
+    }
+    /// Stops events from the gamepad being used until its enabled again, resetting its input.
+    /// Its binds are kept, useful for drop in drop out co-op or muting a gamepad thats been left.
+    #[cfg(feature = "gamepad")]
+    pub fn set_gamepad_enabled(&mut self, id: gilrs::GamepadId, enabled: bool) {
+        self.disabled_gamepads.retain(|&i| i != id);
+        if !enabled {
+            self.disabled_gamepads.push(id);
+            self.reset_gamepad(id);
+        }
+    }
+    /// the value of the most pressed button of the gamepad, or of every gamepad if its `None`
+    #[cfg(feature = "gamepad")]
+    fn max_button(&self, id: Option<gilrs::GamepadId>) -> f32 {
+        self.button_values.iter().filter(|((i, _), _)| id.is_none_or(|id| *i == id))
+            .fold(0.0, |max, (_, &v)| max.max(v))
+    }
+    /// When the most recent gamepad event was recieved by gilrs, `None` if there hasn't been any.
+    /// Comparing it to the current time gives how long it took for the input to be used, useful
+    /// for measuring input latency.
+    #[cfg(feature = "gamepad")]
+    pub fn gamepad_event_time(&self) -> Option<std::time::SystemTime> {
+        self.gamepad_event_time
+    }
+    /// The gamepad that last pressed the action, even if its bound to any gamepad. Useful for
+    /// showing button prompts for the gamepad the player is using.
+    #[cfg(feature = "gamepad")]
+    pub fn last_gamepad(&self, action: F) -> Option<gilrs::GamepadId> {
+        match self.press_sources.1.get(&action) {
+            Some(InputCode::Gamepad { id: SpecifyGamepad::Id(id), .. }) => Some(*id),
+            _ => None
+        }
+    }
+    /// Returns the kind of gamepad, e.g. Xbox or PlayStation, based off its vendor and name. Useful
+    /// for showing the right button prompts. Returns `Generic` if the gamepad hasn't sent any
+    /// events yet.
+    #[cfg(feature = "gamepad")]
+    pub fn gamepad_kind(&self, id: gilrs::GamepadId) -> GamepadKind {
+        self.gamepads.get(&id).map(|g| g.0).unwrap_or_default()
+    }
+    /// Returns the name of the gamepad, e.g. "Xbox Wireless Controller", or `None` if it hasn't
+    /// sent any events yet. Useful for showing which gamepad each player is using.
+    #[cfg(feature = "gamepad")]
+    pub fn gamepad_name(&self, id: gilrs::GamepadId) -> Option<String> {
+        self.gamepads.get(&id).map(|g| g.1.clone())
+    }
+    /// Rumbles the gamepad for the duration, `strong` and `weak` being how hard to rumble the
+    /// strong and weak motors from 0 to 1. The effect is kept until its finished so there's no
+    /// need to manage it.
+    #[cfg(feature = "gamepad")]
+    pub fn rumble_for(
+        &mut self, gilrs: &mut gilrs::Gilrs, gamepad: gilrs::GamepadId,
+        strong: f32, weak: f32, duration: Duration
+    ) -> Result<(), gilrs::ff::Error> {
+        use gilrs::ff::{BaseEffect, BaseEffectType, EffectBuilder, Repeat, Replay, Ticks};
+        let ticks = Ticks::from_ms(duration.as_millis().min(u32::MAX as u128) as u32);
+        let motor = |kind| BaseEffect {
+            kind, scheduling: Replay { play_for: ticks, ..Default::default() }, ..Default::default()
+        };
+        let magnitude = |v: f32| (v.clamp(0.0, 1.0) * u16::MAX as f32) as u16;
+        let effect = EffectBuilder::new()
+            .add_effect(motor(BaseEffectType::Strong { magnitude: magnitude(strong) }))
+            .add_effect(motor(BaseEffectType::Weak { magnitude: magnitude(weak) }))
+            .repeat(Repeat::For(ticks))
+            .gamepads(&[gamepad])
+            .finish(gilrs)?;
+        effect.play()?;
+        self.rumbles.push((effect, std::time::Instant::now() + duration));
+        Ok(())
+    }
+    /// Rumbles the gamepad with `rumble_for` if the action was just pressed.
+    /// ```ignore
+    /// input.rumble_on_pressed(&mut gilrs, Fire, gamepad, 0.8, 0.4, Duration::from_millis(100))?;
+    /// ```
+    #[cfg(feature = "gamepad")]
+    pub fn rumble_on_pressed(
+        &mut self, gilrs: &mut gilrs::Gilrs, action: F, gamepad: gilrs::GamepadId,
+        strong: f32, weak: f32, duration: Duration
+    ) -> Result<(), gilrs::ff::Error> {
+        if self.pressed(action) { self.rumble_for(gilrs, gamepad, strong, weak, duration) }
+        else { Ok(()) }
+    }
+    /// How hard a gamepad button is being pressed from 0 to 1. Most gamepads only report 0 or 1
+    /// but some have pressure sensitive buttons. If any gamepad is specified the highest
+    /// pressure is returned.
+    #[cfg(feature = "gamepad")]
+    pub fn button_pressure(&self, button: GamepadInput, gamepad: SpecifyGamepad) -> f32 {
+        match gamepad {
+            SpecifyGamepad::Id(id) => self.button_values.get(&(id, button)).copied().unwrap_or(0.0),
+            SpecifyGamepad::Any => self.button_values.iter().filter(|((_, b), _)| *b == button)
+                .map(|(_, v)| *v).fold(0.0, f32::max)
+        }
+    }
+    /// Updates the gyroscope of a gamepad with its rotation rates, pitching up, yawing right and
+    /// rolling right being positive. These can then be bound like sticks with `GyroPitchUp`,
+    /// `GyroYawLeft` and so on, which is useful for gyro aiming. gilrs doesn't read motion sensors
+    /// so the rates have to come from elsewhere, gamepads without them are just left at 0.
+    #[cfg(feature = "gamepad")]
+    pub fn update_gyro(&mut self, id: gilrs::GamepadId, pitch: f32, yaw: f32, roll: f32) {
+        use GamepadInput::*;
+        for (pos, neg, v) in [(GyroPitchUp, GyroPitchDown, pitch), (GyroYawRight, GyroYawLeft, yaw),
+         (GyroRollRight, GyroRollLeft, roll)] {
+            self.update_val(pos.with_id(id), v.max(0.0));
+            self.update_val(neg.with_id(id), (-v).max(0.0));
+        }
+    }
+    /// Sets the stick deadzone for one gamepad, overriding `stick_deadzone`. Useful since
+    /// different gamepads have different amounts of stick drift.
+    #[cfg(feature = "gamepad")]
+    pub fn set_gamepad_deadzone(&mut self, id: gilrs::GamepadId, deadzone: f32) {
+        self.gamepad_deadzones.insert(id, deadzone);
+    }
+    /// Makes the gamepad use `stick_deadzone` again.
+    #[cfg(feature = "gamepad")]
+    pub fn clear_gamepad_deadzone(&mut self, id: gilrs::GamepadId) {
+        self.gamepad_deadzones.remove(&id);
+    }
+    /// The stick deadzone used for the gamepad.
+    #[cfg(feature = "gamepad")]
+    pub fn gamepad_deadzone(&self, id: gilrs::GamepadId) -> f32 {
+        self.gamepad_deadzones.get(&id).copied().unwrap_or(self.stick_deadzone)
+    }
+    /// the position of a stick, 0 being left and 1 being right. If any gamepad is specified the
+    /// stick thats moved the furthest is used.
+    #[cfg(feature = "gamepad")]
+    fn stick(&self, gamepad: SpecifyGamepad, stick: usize, deadzone: bool) -> (f32, f32) {
+        let get = |(id, s): (&gilrs::GamepadId, &[f32; 4])| {
+            let (x, y) = (s[stick * 2], s[stick * 2 + 1]);
+            if deadzone { radial_deadzone(x, y, self.gamepad_deadzone(*id)) } else { (x, y) }
+        };
+        match gamepad {
+            SpecifyGamepad::Id(id) => self.sticks.get_key_value(&id).map(get).unwrap_or((0.0, 0.0)),
+            SpecifyGamepad::Any => self.sticks.iter().map(get)
+                .max_by(|a, b| (a.0*a.0 + a.1*a.1).total_cmp(&(b.0*b.0 + b.1*b.1)))
+                .unwrap_or((0.0, 0.0))
+        }
+    }
+    /// Returns the position of the left stick with the gamepads deadzone applied, up and right
+    /// being positive. The length is at most 1.
+    #[cfg(feature = "gamepad")]
+    pub fn left_stick<V: Vec2Like>(&self, gamepad: SpecifyGamepad) -> V {
+        let (x, y) = self.stick(gamepad, 0, true);
+        V::new(x, y)
+    }
+    /// Returns the position of the right stick with the gamepads deadzone applied, up and right
+    /// being positive. The length is at most 1.
+    #[cfg(feature = "gamepad")]
+    pub fn right_stick<V: Vec2Like>(&self, gamepad: SpecifyGamepad) -> V {
+        let (x, y) = self.stick(gamepad, 1, true);
+        V::new(x, y)
+    }
+    /// Returns the position of the left stick exactly as the gamepad reported it, without any
+    /// deadzone. Useful for calibration screens, for everything else see `left_stick`.
+    #[cfg(feature = "gamepad")]
+    pub fn raw_left_stick<V: Vec2Like>(&self, gamepad: SpecifyGamepad) -> V {
+        let (x, y) = self.stick(gamepad, 0, false);
+        V::new(x, y)
+    }
+    /// Returns the position of the right stick exactly as the gamepad reported it, without any
+    /// deadzone. Useful for calibration screens, for everything else see `right_stick`.
+    #[cfg(feature = "gamepad")]
+    pub fn raw_right_stick<V: Vec2Like>(&self, gamepad: SpecifyGamepad) -> V {
+        let (x, y) = self.stick(gamepad, 1, false);
+        V::new(x, y)
+    }
+    /// Checks if the left stick is pushed past its deadzone. Useful for switching to gamepad
+    /// button prompts as soon as its touched.
+    #[cfg(feature = "gamepad")]
+    pub fn left_stick_moved(&self, gamepad: SpecifyGamepad) -> bool {
+        self.stick(gamepad, 0, true) != (0.0, 0.0)
+    }
+    /// Checks if the right stick is pushed past its deadzone.
+    #[cfg(feature = "gamepad")]
+    pub fn right_stick_moved(&self, gamepad: SpecifyGamepad) -> bool {
+        self.stick(gamepad, 1, true) != (0.0, 0.0)
+    }
+    /// Checks if action is being pressed currently based on the `press_sensitivity`, or the
+    /// actions own sensitivity if its been set with `set_action_sensitivity`. Same as
+    /// `self.value(action) >= self.press_sensitivty` unless the action has a minimum number of
+    /// binds set with `set_min_binds`.
+    pub fn pressing(&self, action: F) -> bool {
+        self.unsmoothed_value(action) >= self.sensitivity(action) && self.enough_binds(action)
+    }
+    /// Checks if the action is being held down, same as `pressing`. Unlike `pressed` this is true
+    /// for every loop the action is held, not just the one it started on.
+    pub fn is_down(&self, action: F) -> bool {
+        self.pressing(action)
+    }
+    /// Checks if the action isn't being held down, same as `!pressing`. Unlike `released` this is
+    /// true for every loop the action isn't held, not just the one it was let go on.
+    pub fn is_up(&self, action: F) -> bool {
+        !self.pressing(action)
+    }
+    /// the sensitivity the action uses, its own if it has one or the `press_sensitivity`
+    fn sensitivity(&self, action: F) -> f32 {
+        self.action_sensitivity.get(&action).copied().unwrap_or(self.press_sensitivity)
+    }
+    /// Sets the `press_sensitivity` and updates if each action is pressed with it, so an action
+    /// held between the old and new sensitivity is `pressed` or `released` straight away instead
+    /// of when its value next changes. Useful for settings menus.
+    pub fn set_press_sensitivity(&mut self, sensitivity: f32) {
+        let was_pressing: Vec<_> = self.action_val.keys().map(|&a| (a, self.pressing(a))).collect();
+        self.press_sensitivity = sensitivity;
+        self.update_pressing(was_pressing);
+    }
+    /// Gives the action its own `press_sensitivity`, e.g. so a trigger bound to accelerate counts
+    /// as pressed at 0.1 while a stick bound to dash only counts at 0.9. Like
+    /// `set_press_sensitivity` the action is `pressed` or `released` straight away if needed.
+    pub fn set_action_sensitivity(&mut self, action: F, sensitivity: f32) {
+        let was_pressing = vec![(action, self.pressing(action))];
+        self.action_sensitivity.insert(action, sensitivity);
+        self.update_pressing(was_pressing);
+    }
+    /// Makes the action go back to using the `press_sensitivity`
+    pub fn clear_action_sensitivity(&mut self, action: F) {
+        let was_pressing = vec![(action, self.pressing(action))];
+        self.action_sensitivity.remove(&action);
+        self.update_pressing(was_pressing);
+    }
+    /// sets `pressed` and `released` for actions that started or stopped pressing without their
+    /// value changing
+    fn update_pressing(&mut self, was_pressing: Vec<(F, bool)>) {
+        for (action, was_pressing) in was_pressing {
+            let now_pressing = self.pressing(action);
+            let Some((_, pressed, released, _)) = self.action_val.get_mut(&action) else { continue };
+            if now_pressing && !was_pressing {
+                *pressed = true;
+                self.press_times.insert(action, self.time.0);
+            }
+            if !now_pressing && was_pressing { *released = true }
+        }
+    }
+    /// checks if enough of the actions binds are pressed for `set_min_binds`
+    fn enough_binds(&self, action: F) -> bool {
+        let Some(&min) = self.min_binds.get(&action).filter(|&&min| min > 1) else { return true };
+        let Some((_, _, _, sub_values)) = self.action_val.get(&action) else { return false };
+        sub_values.iter().filter(|(v, _)| *v * self.global_scale >= self.sensitivity(action)).count() >= min
+    }
+    /// Checks how much an action is being pressed. May be higher than 1 in the case of scroll
+    /// wheels, mouse movement or when multiple binds are bound to an action.
+    pub fn value(&self, action: F) -> f32 {
+        let Some(&(_, v)) = self.smoothing.get(&action) else { return self.unsmoothed_value(action) };
+        if self.clamp_to_unit { v.clamp(0.0, 1.0) } else { v }
+    }
+    /// the value of the action ignoring `set_smoothing`
+    fn unsmoothed_value(&self, action: F) -> f32 {
+        let v = if let Some(&(v, _, _, _)) = self.action_val.get(&action) { v } else {  0.0  };
+        let v = v * self.global_scale;
+        if self.clamp_to_unit { v.clamp(0.0, 1.0) } else { v }
+    }
+    /// Makes `value`, and everything that uses it like `axis` and `dir`, clamp from 0 to 1 so
+    /// stacked binds, scroll wheels and mouse movement can't go past being fully pressed. Off by
+    /// default.
+    pub fn clamp_all_to_unit(&mut self, clamp: bool) {
+        self.clamp_to_unit = clamp;
+    }
+    /// The current value of an input code, even if it isn't bound to anything. Useful for debug
+    /// views that show every input.
+    pub fn code_value(&self, code: InputCode) -> f32 {
+        self.code_values.get(&code).copied().unwrap_or(0.0)
+    }
+    /// Iterates through every bound action and its value. Useful for debug HUDs and telemetry.
+    pub fn values(&self) -> impl Iterator<Item = (F, f32)> + '_ {
+        self.action_val.keys().map(|&action| (action, self.value(action)))
+    }
+    /// Iterates through every action being pressed. Useful for debug overlays.
+    pub fn pressing_actions(&self) -> impl Iterator<Item = F> + '_ {
+        self.action_val.keys().copied().filter(|&action| self.pressing(action))
+    }
+    /// Iterates through every action that was just pressed.
+    pub fn pressed_actions(&self) -> impl Iterator<Item = F> + '_ {
+        self.action_val.keys().copied().filter(|&action| self.pressed(action))
+    }
+    /// Iterates through every action that was just released.
+    pub fn released_actions(&self) -> impl Iterator<Item = F> + '_ {
+        self.action_val.keys().copied().filter(|&action| self.released(action))
+    }
+    /// The index of the bind, in the actions list of binds, thats pressing the action. If several
+    /// binds are pressed the one pressed most recently is used, and if they were pressed at the
+    /// same time the first one is. `None` if no bind is pressed. Useful for showing button prompts
+    /// for the bind the player is using.
+    pub fn triggering_bind(&self, action: F) -> Option<usize> {
+        let (_, _, _, sub_values) = self.action_val.get(&action)?;
+        let order = |bind| self.bind_press_order.1.get(&(action, bind)).copied().unwrap_or(0);
+        sub_values.iter().enumerate()
+            .filter(|(_, (v, _))| *v * self.global_scale >= self.sensitivity(action))
+            .max_by_key(|&(i, _)| (order(i), std::cmp::Reverse(i)))
+            .map(|(i, _)| i)
+    }
+    /// Gives the value of each of the actions binds, in the order they were bound, along with the
+    /// value of each input code in that bind. Useful for debugging which input is triggering an
+    /// action.
+    pub fn bind_values(&self, action: F) -> Vec<(f32, Vec<f32>)> {
+        self.action_val.get(&action).map(|v| v.3.clone()).unwrap_or_default()
+    }
+    /// Maps how much an action is pressed, clamped from 0 to 1, onto the range from `out_min` to
+    /// `out_max`. Useful for things like sliders.
+    /// ```ignore
+    /// let angle = input.value_mapped(Turn, -180.0, 180.0);
+    /// ```
+    pub fn value_mapped(&self, action: F, out_min: f32, out_max: f32) -> f32 {
+        out_min + (out_max - out_min) * self.value(action).clamp(0.0, 1.0)
+    }
+    /// Splits how much an action is pressed, clamped from 0 to 1, into steps from 0 to `steps`.
+    /// Useful for stepped sliders, see `step_changed` for when the step changes.
+    pub fn value_step(&self, action: F, steps: u32) -> u32 {
+        (self.value(action).clamp(0.0, 1.0) * steps as f32).round() as u32
+    }
+    /// Checks if the `value_step` of the action changed this loop.
+    pub fn step_changed(&self, action: F, steps: u32) -> bool {
+        let Some(&start) = self.start_values.get(&action) else { return false };
+        (start.clamp(0.0, 1.0) * steps as f32).round() as u32 != self.value_step(action, steps)
+    }
+    /// Checks if action was just pressed.
+    pub fn pressed(&self, action: F) -> bool {
+        if let Some(&(_, v, _, _)) = self.action_val.get(&action) { v } else { false }
+    }
+    /// How many times the action was pressed this loop. Unlike `pressed` this counts every press
+    /// even when a button is tapped several times in one slow loop, useful for button mashing.
+    pub fn press_count(&self, action: F) -> u32 {
+        self.press_counts.get(&action).copied().unwrap_or(0)
+    }
+    /// Checks if action was just released.
+    pub fn released(&self, action: F) -> bool {
+        if let Some(&(_, _, v, _)) = self.action_val.get(&action) { v } else { false }
+    }
+    /// Checks if action was just released without the mouse going further than `tap_distance`
+    /// from where it was pressed while held. Useful for telling clicks apart from drags.
+    #[cfg(feature = "mice-keyboard")]
+    pub fn tapped(&self, action: F) -> bool {
+        let Some(&(_, furthest)) = self.press_positions.get(&action) else { return false };
+        self.released(action) && furthest < self.tap_distance
+    }
+    /// Makes the action only count as pressed when at least `n` of its binds are pressed at the
+    /// same time, e.g. 2 for an action that needs both a key and a mouse button. Much more
+    /// reliable than raising the `press_sensitivity` since each bind is checked on its own.
+    pub fn set_min_binds(&mut self, action: F, n: usize) {
+        if n <= 1 { self.min_binds.remove(&action); }
+        else { self.min_binds.insert(action, n); }
+    }
+    /// Makes the actions `pressed` stay true through calls to `init` until it is taken with
+    /// `consume`, so slow update loops, like in turn based games, dont miss presses.
+    pub fn set_sticky(&mut self, action: F, sticky: bool) {
+        self.sticky.retain(|&a| a != action);
+        if sticky { self.sticky.push(action) }
+    }
+    /// Checks if the action was pressed and clears it so it isn't `pressed` anymore. Mostly
+    /// useful for actions set with `set_sticky`.
+    pub fn consume(&mut self, action: F) -> bool {
+        let Some((_, pressed, _, _)) = self.action_val.get_mut(&action) else { return false };
+        std::mem::take(pressed)
+    }
+    /// Makes the action fill up by `rate` per second while its being pressed, scaled by how much
+    /// its pressed. Requires `update_with_dt` to be called every loop. See `integrated` for the
+    /// amount its filled, which is kept if the rate is changed.
+    pub fn set_integrating(&mut self, action: F, rate: f32) {
+        self.integrating.entry(action).or_default().0 = rate;
+    }
+    /// Stops the action from filling up and forgets how much it was filled.
+    pub fn stop_integrating(&mut self, action: F) {
+        self.integrating.remove(&action);
+    }
+    /// How much an integrating action has filled up. Returns 0 for actions that aren't
+    /// integrating.
+    pub fn integrated(&self, action: F) -> f32 {
+        self.integrating.get(&action).map(|&(_, filled)| filled).unwrap_or(0.0)
+    }
+    /// Empties an integrating action so it starts filling up from 0 again.
+    pub fn reset_integrated(&mut self, action: F) {
+        if let Some((_, filled)) = self.integrating.get_mut(&action) { *filled = 0.0 }
+    }
+    /// Makes the `value` of the action move towards how much its pressed by `rate` per second
+    /// instead of changing straight away, so keys ramp up and down like a stick. Useful for
+    /// smoothing keyboard movement. The rate is in values after `global_scale`, the same value
+    /// `set_integrating` fills up with. Requires `update_with_dt` to be called every loop. `pressing`, `pressed` and
+    /// `released` aren't smoothed.
+    pub fn set_smoothing(&mut self, action: F, rate: f32) {
+        let current = self.action_val.get(&action).map(|v| v.0 * self.global_scale).unwrap_or(0.0);
+        self.smoothing.entry(action).or_insert((rate, current)).0 = rate;
+    }
+    /// Makes the `value` of the action change straight away again.
+    pub fn clear_smoothing(&mut self, action: F) {
+        self.smoothing.remove(&action);
+    }
+    /// Makes `pressed` fire `rate` times per second while the action is held, like auto-fire.
+    /// Requires `update_with_dt` to be called every loop. Can be changed at any time, e.g. from an
+    /// accessibility menu. Rates of 0 or less, NaN and infinity stop the action from auto-firing
+    /// like `clear_turbo`.
+    pub fn set_turbo(&mut self, action: F, rate: f32) {
+        if !rate.is_finite() || rate <= 0.0 { return self.clear_turbo(action) }
+        self.turbo.entry(action).or_default().0 = rate;
+    }
+    /// Stops the action from auto-firing.
+    pub fn clear_turbo(&mut self, action: F) {
+        self.turbo.remove(&action);
+    }
+    /// How long the action has been held for without being released, or `None` if it isn't being
+    /// pressed. Useful for charge attacks. Uses the time passed to `update_with_dt` instead of the
+    /// system clock so it works with replays and fixed time steps, which means `update_with_dt`
+    /// has to be called every loop. Releasing and pressing again restarts it, even in one loop.
+    pub fn held_duration(&self, action: F) -> Option<Duration> {
+        if !self.pressing(action) { return None }
+        self.press_times.get(&action).map(|&t| self.time.0.saturating_sub(t))
+    }
+    /// How long ago the action was last pressed, even if its been released since, or `None` if it
+    /// hasn't been pressed yet. Useful for combo windows and double taps. Requires
+    /// `update_with_dt` to be called every loop.
+    pub fn time_since_pressed(&self, action: F) -> Option<Duration> {
+        self.press_times.get(&action).map(|&t| self.time.0.saturating_sub(t))
+    }
+    /// Checks if the action has just been held for `after` without being released. Only true for
+    /// the one loop it happens on, making it useful for things like opening context menus.
+    /// Requires `update_with_dt` to be called every loop.
+    pub fn long_pressed(&self, action: F, after: Duration) -> bool {
+        let Some(held) = self.held_duration(action) else { return false };
+        held >= after && held.saturating_sub(self.time.1) < after
+    }
+    /// Turns on or off keeping track of which actions values change, see `take_changes`. Off by
+    /// default to avoid the overhead.
+    pub fn set_change_log(&mut self, enabled: bool) {
+        self.changes = if enabled { Some(self.changes.take().unwrap_or_default()) } else { None };
+    }
+    /// Returns every action whose value changed since the last call, with its value back then and
+    /// its value now. Useful for only reacting to changes, like for a slider bound to a trigger.
+    /// Always empty unless turned on with `set_change_log`.
+    pub fn take_changes(&mut self) -> Vec<(F, f32, f32)> {
+        let Some(changes) = &mut self.changes else { return vec![] };
+        changes.retain(|&(_, old, new)| old != new);
+        std::mem::take(changes)
+    }
+    /// Sets how many loops of history are kept for `was_pressed_ago`. The history is recorded in
+    /// `init` and is off by default, setting it to 0 turns it off again.
+    pub fn set_history_capacity(&mut self, capacity: usize) {
+        self.history_capacity = capacity;
+        self.history.truncate(capacity);
+    }
+    /// Checks if action was just pressed the inputed number of loops ago, 0 being this loop. Only
+    /// works as far back as the capacity set with `set_history_capacity`, returning false past it.
+    /// Useful for debugging and replays.
+    pub fn was_pressed_ago(&self, action: F, frames: usize) -> bool {
+        if frames == 0 { return self.pressed(action) }
+        self.history.get(frames - 1).is_some_and(|pressed| pressed.contains(&action))
+    }
+    /// Copies the current state of every action so it can be compared or restored later.
+    pub fn snapshot(&self) -> InputSnapshot<F> {
+        InputSnapshot { action_val: self.action_val.clone() }
+    }
+    /// Sets every action back to how it was in the snapshot. Actions whose binds have changed
+    /// since the snapshot was made are left as they are.
+    pub fn restore(&mut self, snapshot: &InputSnapshot<F>) {
+        for (action, val) in self.action_val.iter_mut() {
+            let Some(old) = snapshot.action_val.get(action) else { continue };
+            let same_binds = old.3.len() == val.3.len()
+                && old.3.iter().zip(val.3.iter()).all(|(a, b)| a.1.len() == b.1.len());
+            if same_binds { *val = old.clone() }
+        }
+    }
+    /// Packs whether each of the inputed actions is being pressed into one bit each, in the order
+    /// they were given. Much smaller than a snapshot so its useful for sending input over the
+    /// network every tick. See `decode_frame` to apply it to another input map.
+    pub fn encode_frame(&self, actions: &[F]) -> Vec<u8> {
+        let mut bytes = vec![0; actions.len().div_ceil(8)];
+        for (i, &action) in actions.iter().enumerate() {
+            if self.pressing(action) { bytes[i / 8] |= 1 << (i % 8) }
+        }
+        bytes
+    }
+    /// Applies a frame made with `encode_frame`, the actions must be in the same order they were
+    /// encoded in. `pressed` and `released` are worked out from the previous frame. Meant for
+    /// input maps that only recieve decoded frames, like a remote players, since it overwrites the
+    /// actions values. Pressed actions get the smallest value that counts as pressing with the
+    /// maps `global_scale`, sensitivities and `set_min_binds`.
+    pub fn decode_frame(&mut self, actions: &[F], bytes: &[u8]) {
+        for (i, &action) in actions.iter().enumerate() {
+            let now_pressing = bytes.get(i / 8).is_some_and(|b| b & (1 << (i % 8)) != 0);
+            let was_pressing = self.pressing_unclamped(action);
+            // the smallest bind value that presses the action, given to as many binds as
+            // `set_min_binds` needs. It can't be pressed at all without a positive `global_scale`
+            let scale = self.global_scale;
+            let value = if scale > 0.0 { (self.sensitivity(action) / scale).max(1.0) } else { 0.0 };
+            let needed = self.min_binds.get(&action).copied().unwrap_or(1).max(1);
+            let mode = self.combine_modes.get(&action).copied().unwrap_or_default();
+
+            let (val, _, _, sub_values) = self.action_val.entry(action).or_default();
+            let old_val = *val;
+            for (bind, sub_value) in sub_values.iter_mut().enumerate() {
+                sub_value.0 = if now_pressing && bind < needed { value } else { 0.0 };
+            }
+            *val = if !now_pressing { 0.0 } else if sub_values.is_empty() { value } else { combine(sub_values, mode) };
+            self.action_changed(action, old_val, was_pressing, None);
+        }
+    }
+    /// The highest value the action reached while it was being pressed. Only valid on the loop its
+    /// `released`, returns 0 otherwise. Useful for things like drawing a bow with a trigger.
+    pub fn released_value(&self, action: F) -> f32 {
+        if self.released(action) { self.peaks.get(&action).map(|p| p * self.global_scale).unwrap_or(0.0) } else { 0.0 }
+    }
+    /// How long the action was held for and the highest value it reached, only on the loop its
+    /// `released`. Useful for things like a charged jump that uses both how long and how hard it
+    /// was pressed. Requires `update_with_dt` to be called every loop for the duration.
+    pub fn release_info(&self, action: F) -> Option<(Duration, f32)> {
+        if !self.released(action) { return None }
+        let held = self.press_times.get(&action).map(|&t| self.time.0.saturating_sub(t)).unwrap_or_default();
+        Some((held, self.released_value(action)))
+    }
+    /// Returns f32 based on how much pos and neg are pressed. may return values higher than 1.0 in
+    /// the case of mouse movement and scrolling. usefull for movement controls. for 2d values see
+    /// `dir` and `dir_max_len_1`
+    /// ```ignore
+    /// let move_dir = input.axis(Neg, Pos);
+    /// ```
+    /// same as `input.value(pos) - input.value(neg)`
+    pub fn axis(&self, pos: F, neg: F) -> f32 {
+        self.value(pos) - self.value(neg)
+    }
+    /// Same as `axis` but with a different way of handling both directions being pressed at once
+    /// (simultaneous opposing cardinal directions), instead of them cancelling out. Common in
+    /// fighting games.
+    /// ```ignore
+    /// let walk = input.axis_socd(Right, Left, SocdMode::LastWins);
+    /// ```
+    pub fn axis_socd(&self, pos: F, neg: F, mode: SocdMode) -> f32 {
+        if !self.pressing(pos) || !self.pressing(neg) { return self.axis(pos, neg) }
+        let order = |action| self.press_order.1.get(&action).copied().unwrap_or(0);
+        match mode {
+            SocdMode::Neutral => 0.0,
+            SocdMode::LastWins if order(neg) > order(pos) => -self.value(neg),
+            SocdMode::LastWins | SocdMode::PositivePriority => self.value(pos)
+        }
+    }
+    /// Same as `axis` but returns 0 when the axis is within the deadzone and rescales the rest so
+    /// it starts from 0 at the edge of the deadzone, clamped from -1 to 1. Useful for stopping
+    /// stick drift on things like steering.
+    pub fn axis_deadzone(&self, pos: F, neg: F, deadzone: f32) -> f32 {
+        let axis = self.axis(pos, neg);
+        if axis.abs() <= deadzone { return 0.0 }
+        axis.signum() * ((axis.abs() - deadzone) / (1.0 - deadzone)).min(1.0)
+    }
+    /// Returns a vector based off of the x and y axis. Can return values with a length higher than
+    /// 1, if this is undesirable see `dir_max_len_1`. Can return any type that implements
+    /// `Vec2Like`, such as `(f32, f32)`.
+    pub fn dir<V: Vec2Like>(&self, pos_x: F, neg_x: F, pos_y: F, neg_y: F) -> V {
+        V::new(self.axis(pos_x, neg_x), self.axis(pos_y, neg_y))
+    }
+    /// Returns a vector based off of x and y axis with a maximum length of 1 (the same as a normalised
+    /// vector). If this undesirable see `dir`.
+    pub fn dir_max_len_1<V: Vec2Like>(&self, pos_x: F, neg_x: F, pos_y: F, neg_y: F) -> V {
+        let (x, y) = (self.axis(pos_x, neg_x), self.axis(pos_y, neg_y));
+        // if lower than 1, set to 1. since x/1 = x, that means anything lower than 1 is left unchanged
+        let length = (x*x + y*y).sqrt().max(1.0);
+        V::new(x/length, y/length)
+    }
+    /// Same as `dir_max_len_1` but returns 0 when the vector is shorter than the deadzone and
+    /// rescales the rest so it starts from 0 at the edge of the deadzone. Useful for stopping
+    /// stick drift in movement.
+    pub fn dir_clamped_deadzone<V: Vec2Like>(&self, pos_x: F, neg_x: F, pos_y: F, neg_y: F, deadzone: f32) -> V {
+        let (x, y) = radial_deadzone(self.axis(pos_x, neg_x), self.axis(pos_y, neg_y), deadzone);
+        V::new(x, y)
+    }
+}
+#[cfg(feature = "serde")]
+impl<F: Hash + Copy + Eq> InputMap<F> {
+    /// Returns the binds ready to be saved with serde, so players can edit them in a config file.
+    /// Device and gamepad ids are set to any since they change every time the program runs.
+    pub fn export_binds(&self) -> Binds<F> {
+        self.get_binds().into_iter()
+            .map(|(action, binds)| (action, binds.into_iter().map(|b| b.into_iter().map(InputCode::set_any).collect()).collect()))
+            .collect()
+    }
+    /// Replaces the binds with ones loaded with serde, see `export_binds`
+    pub fn import_binds(&mut self, binds: &Binds<F>) {
+        self.set_binds(binds);
+    }
+}
+#[cfg(test)]
+#[allow(clippy::vec_init_then_push)]
+mod tests {
+    // every test needs input codes so none are built with both features off
+    #[cfg_attr(not(any(feature = "mice-keyboard", feature = "gamepad")), allow(unused_imports))]
+    use super::*;
+    extern crate test;
+    /// gamepad ids can't be made outside of gilrs, so they're loaded instead
+    #[cfg(feature = "gamepad")]
+    fn gamepad_id(id: usize) -> gilrs::GamepadId {
+        ron::from_str(&format!("({id})")).unwrap()
+    }
+    /// a gilrs event from the gamepad, the event code is never read so any is used
+    #[cfg(feature = "gamepad")]
+    fn axis_event(id: gilrs::GamepadId, axis: gilrs::Axis, value: f32) -> gilrs::Event {
+        let code = gilrs::Button::South.to_nec().unwrap();
+        gilrs::Event::new(id, gilrs::EventType::AxisChanged(axis, value, code))
+    }
+    #[cfg(feature = "gamepad")]
+    fn button_event(id: gilrs::GamepadId, button: gilrs::Button, value: f32) -> gilrs::Event {
+        let code = button.to_nec().unwrap_or(gilrs::Button::South.to_nec().unwrap());
+        gilrs::Event::new(id, gilrs::EventType::ButtonChanged(button, value, code))
+    }
+    /// compare hashers by running it with and without the `fast-hash` feature
+    #[bench]
+    #[cfg(feature = "mice-keyboard")]
+    fn bench_input(b: &mut test::Bencher) {
+        use Action::*;
+        #[derive(PartialEq, Eq, Clone, Copy, Hash)]
+        enum Action {
+            Test1,
+            Test2,
+            Test3,
+            Test4,
+        }
+        let mut input = { use base_input_codes::*; crate::input_map!(
+            (Test1, KeyI, [ControlLeft, KeyZ], [ShiftLeft, KeyZ]),
+            (Test2, [ControlLeft, KeyS], KeyZ, KeyU),
+            (Test3, [KeyX, KeyI], [KeyS, KeyV, ControlLeft, KeyZ]),
+            (Test4, KeyZ)
+        ) };
+        
+        b.iter(|| {
+            input.update_val(base_input_codes::KeyZ.into(), 1.0);
+            input.update_val(base_input_codes::ControlLeft.into(), 1.0);
+        });
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn integrating() {
+        let mut input = crate::input_map!((0, base_input_codes::KeyZ), (1, base_input_codes::KeyX));
+        input.set_integrating(0, 2.0);
+
+        input.update_with_dt(Duration::from_secs(1));
+        assert_eq!(input.integrated(0), 0.0);
+
+        input.update_val(base_input_codes::KeyZ.into(), 1.0);
+        input.update_with_dt(Duration::from_millis(500));
+        input.update_with_dt(Duration::from_millis(500));
+        assert_eq!(input.integrated(0), 2.0);
+        assert_eq!(input.integrated(1), 0.0);
+
+        input.reset_integrated(0);
+        assert_eq!(input.integrated(0), 0.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn snapshot_diff() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ), (1, KeyX), (2, KeyC));
+        input.update_val(KeyX.into(), 1.0);
+        let old = input.snapshot();
+
+        input.update_val(KeyZ.into(), 1.0);
+        input.update_val(KeyX.into(), 0.0);
+        let mut diff = old.diff(&input.snapshot());
+        diff.sort_by_key(|&(a, _, _)| a);
+        assert_eq!(diff, vec![(0, 0.0, 1.0), (1, 1.0, 0.0)]);
+
+        input.restore(&old);
+        assert_eq!(input.value(0), 0.0);
+        assert_eq!(input.value(1), 1.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn encode_decode_frame() {
+        use base_input_codes::*;
+        let actions: Vec<i32> = (0..10).collect();
+        let mut input = crate::input_map!((0, KeyZ), (9, KeyX));
+        input.update_val(KeyZ.into(), 1.0);
+        input.update_val(KeyX.into(), 1.0);
+        let frame = input.encode_frame(&actions);
+        assert_eq!(frame, vec![0b1, 0b10]);
+
+        let mut remote = InputMap::default();
+        remote.decode_frame(&actions, &frame);
+        assert!(remote.pressed(0) && remote.pressed(9) && !remote.pressing(1));
+        remote.init();
+        remote.decode_frame(&actions, &[0, 0b10]);
+        assert!(remote.released(0) && remote.pressing(9) && !remote.pressed(9));
+
+        // the remote map can scale values down and need several binds
+        let mut remote = crate::input_map!((0, KeyZ, KeyX, KeyC));
+        remote.global_scale = 0.25;
+        remote.set_min_binds(0, 2);
+        remote.set_change_log(true);
+        remote.decode_frame(&actions, &frame);
+        assert!(remote.pressed(0) && remote.pressing(0));
+        assert!(remote.value(0) >= remote.press_sensitivity);
+        assert_eq!(remote.take_changes().len(), 2);
+        assert_eq!(remote.press_count(0), 1);
+        remote.init();
+        remote.decode_frame(&actions, &[0, 0]);
+        assert!(remote.released(0) && !remote.pressing(9));
+        assert_eq!(remote.value(0), 0.0);
+        assert_eq!(remote.bind_values(0).iter().map(|b| b.0).sum::<f32>(), 0.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn add_binds_to_bound_action() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ));
+        input.add_binds(&crate::binds!((0, KeyX)));
+        input.update_val(KeyX.into(), 1.0);
+        assert!(input.pressing(0));
+
+        input.set_binds(&crate::binds!((0, KeyC)));
+        assert_eq!(input.value(0), 0.0);
+        input.update_val(KeyC.into(), 1.0);
+        assert!(input.pressing(0));
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn deadzone() {
+        assert_eq!(radial_deadzone(0.05, 0.05, 0.1), (0.0, 0.0));
+        assert_eq!(radial_deadzone(0.0, 0.75, 0.5), (0.0, 0.5));
+        assert_eq!(radial_deadzone(-2.0, 0.0, 0.1), (-1.0, 0.0));
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn released_value() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, RightTrigger));
+        input.update_val(RightTrigger.into(), 0.6);
+        input.update_val(RightTrigger.into(), 0.9);
+        input.update_val(RightTrigger.into(), 0.7);
+        assert_eq!(input.released_value(0), 0.0);
+        input.init();
+        input.update_val(RightTrigger.into(), 0.0);
+        assert_eq!(input.released_value(0), 0.9);
+        input.init();
+        assert_eq!(input.released_value(0), 0.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn turbo() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ));
+        input.set_turbo(0, 4.0);
+        input.update_val(KeyZ.into(), 1.0);
+        input.update_with_dt(Duration::from_millis(125));
+        assert!(input.pressed(0));
+        input.init();
+        input.update_with_dt(Duration::from_millis(125));
+        assert!(!input.pressed(0));
+        input.init();
+        input.update_with_dt(Duration::from_millis(125));
+        assert!(input.pressed(0));
+        input.init();
+        input.update_val(KeyZ.into(), 0.0);
+        input.update_with_dt(Duration::from_millis(250));
+        assert!(!input.pressed(0));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn turbo_invalid_rate() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ));
+        input.update_val(KeyZ.into(), 1.0);
+        input.init();
+        for rate in [0.0, -4.0, f32::NAN] {
+            input.set_turbo(0, rate);
+            input.update_with_dt(Duration::from_millis(125));
+            assert!(!input.pressed(0));
+        }
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn long_pressed() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ));
+        let (step, after) = (Duration::from_millis(100), Duration::from_millis(250));
+        input.update_val(KeyZ.into(), 1.0);
+        let fired: Vec<bool> = (0..5).map(|_| {
+            input.update_with_dt(step);
+            let fired = input.long_pressed(0, after);
+            input.init();
+            fired
+        }).collect();
+        assert_eq!(fired, vec![false, false, true, false, false]);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn swipes() {
+        let mut input = crate::input_map!();
+        let touch = |input: &mut InputMap<()>, phase, x, y| input.update_with_window_event(&WindowEvent::Touch(Touch {
+            device_id: DeviceId::dummy(), phase, location: PhysicalPosition::new(x, y), force: None, id: 0
+        }));
+        touch(&mut input, TouchPhase::Started, 0.0, 0.0);
+        touch(&mut input, TouchPhase::Ended, -100.0, 10.0);
+        touch(&mut input, TouchPhase::Started, 0.0, 0.0);
+        touch(&mut input, TouchPhase::Ended, 10.0, 10.0);
+        input.update_with_dt(Duration::from_secs(1));
+        touch(&mut input, TouchPhase::Started, 0.0, 0.0);
+        input.update_with_dt(Duration::from_secs(1));
+        touch(&mut input, TouchPhase::Ended, 0.0, 100.0);
+        assert_eq!(input.take_swipes(), vec![SwipeDirection::Left]);
+        assert_eq!(input.take_swipes(), vec![]);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn ime() {
+        let mut input = crate::input_map!();
+        input.update_with_window_event(&WindowEvent::Ime(Ime::Preedit("にほ".into(), Some((6, 6)))));
+        assert_eq!(input.ime_preedit(), Some(("にほ".into(), Some((6, 6)))));
+        assert_eq!(input.text_typed, None);
+        input.update_with_window_event(&WindowEvent::Ime(Ime::Preedit("にほん".into(), None)));
+        assert_eq!(input.ime_cursor(), Some((9, 9)));
+
+        input.update_with_window_event(&WindowEvent::Ime(Ime::Commit("日本".into())));
+        assert_eq!(input.ime_preedit(), None);
+        assert_eq!(input.text_typed.as_deref(), Some("日本"));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn tapped() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, MouseButton::Left));
+        let click = |input: &mut InputMap<i32>, x, y| {
+            input.update_mouse(PhysicalPosition::new(0.0, 0.0));
+            input.update_val(MouseButton::Left.into(), 1.0);
+            input.update_mouse(PhysicalPosition::new(x, y));
+            input.update_val(MouseButton::Left.into(), 0.0);
+            input.tapped(0)
+        };
+        assert!(click(&mut input, 3.0, 4.0));
+        assert!(!click(&mut input, 30.0, 40.0));
+        // dragging away and back isn't a tap even though it ends where it started
+        input.update_val(MouseButton::Left.into(), 1.0);
+        input.update_mouse(PhysicalPosition::new(50.0, 0.0));
+        input.update_mouse(PhysicalPosition::new(0.0, 0.0));
+        input.update_val(MouseButton::Left.into(), 0.0);
+        assert!(!input.tapped(0));
+        // and moving after releasing doesn't stop it being a tap
+        assert!(click(&mut input, 1.0, 1.0));
+        input.update_mouse(PhysicalPosition::new(50.0, 0.0));
+        assert!(input.tapped(0));
+    }
+    #[test]
+    #[cfg(all(feature = "mice-keyboard", feature = "gamepad"))]
+    fn change_log() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, LeftTrigger), (1, KeyZ));
+        input.update_val(LeftTrigger.into(), 0.5);
+        assert_eq!(input.take_changes(), vec![]);
+
+        input.set_change_log(true);
+        input.update_val(LeftTrigger.into(), 0.7);
+        input.update_val(LeftTrigger.into(), 0.9);
+        input.update_val(KeyZ.into(), 1.0);
+        input.update_val(KeyZ.into(), 0.0);
+        assert_eq!(input.take_changes(), vec![(0, 0.5, 0.9)]);
+        assert_eq!(input.take_changes(), vec![]);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn text_excluding() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, Enter), (1, KeyA));
+        input.push_text("a", Some(KeyA.into()));
+        input.update_val(Enter.into(), 1.0);
+        input.push_text("\r", Some(Enter.into()));
+        assert_eq!(input.text_excluding(&[0]).as_deref(), Some("a"));
+        assert_eq!(input.text_excluding(&[]).as_deref(), Some("a\r"));
+        assert_eq!(input.text_typed.as_deref(), Some("a\r"));
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn value_step() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, LeftTrigger));
+        input.update_val(LeftTrigger.into(), 0.52);
+        assert_eq!(input.value_step(0, 4), 2);
+        assert!(input.step_changed(0, 4));
+        input.init();
+        input.update_val(LeftTrigger.into(), 0.55);
+        assert!(!input.step_changed(0, 4));
+        input.update_val(LeftTrigger.into(), 1.0);
+        assert_eq!(input.value_step(0, 4), 4);
+        assert!(input.step_changed(0, 4));
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn simultaneous() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, [LeftBumper, RightBumper]));
+        input.set_simultaneous(0, 0, true);
+        input.update_val(LeftBumper.into(), 1.0);
+        input.init();
+        input.update_val(RightBumper.into(), 1.0);
+        assert!(!input.pressing(0));
+
+        input.update_val(LeftBumper.into(), 0.0);
+        input.update_val(RightBumper.into(), 0.0);
+        input.init();
+        input.update_val(LeftBumper.into(), 1.0);
+        input.update_val(RightBumper.into(), 1.0);
+        assert!(input.pressed(0));
+        input.init();
+        input.update_val(LeftBumper.into(), 0.0);
+        assert!(input.released(0));
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn min_binds() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, LeftBumper, RightBumper));
+        input.set_min_binds(0, 2);
+        input.update_val(LeftBumper.into(), 1.0);
+        assert!(!input.pressing(0));
+        input.update_val(RightBumper.into(), 1.0);
+        assert!(input.pressed(0));
+        input.init();
+        input.update_val(LeftBumper.into(), 0.0);
+        assert!(input.released(0));
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn press_count() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, South));
+        for _ in 0..3 {
+            input.update_val(South.into(), 1.0);
+            input.update_val(South.into(), 0.0);
+        }
+        assert_eq!(input.press_count(0), 3);
+        input.init();
+        assert_eq!(input.press_count(0), 0);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn code_value() {
+        use base_input_codes::*;
+        let mut input = InputMap::empty();
+        input.update_val(South.into(), 0.5);
+        assert_eq!(input.code_value(South.into()), 0.5);
+        assert_eq!(input.code_value(North.into()), 0.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn recent_inputs() {
+        use base_input_codes::*;
+        let id = DeviceId::dummy();
+        let (key_x, key_z) = (DeviceInput::from(KeyX).with_id(id), DeviceInput::from(KeyZ).with_id(id));
+        let mut input = crate::input_map!((0, KeyZ));
+        input.update_val(key_x, 1.0);
+        input.update_val(key_z, 1.0);
+        input.update_val(key_x, 0.8);
+        assert_eq!(input.take_recent_inputs(), vec![key_x, key_z]);
+        assert!(input.take_recent_inputs().is_empty());
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn axis_socd() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, DPadRight), (1, DPadLeft));
+        input.update_val(DPadRight.into(), 1.0);
+        input.update_val(DPadLeft.into(), 1.0);
+        assert_eq!(input.axis_socd(0, 1, SocdMode::Neutral), 0.0);
+        assert_eq!(input.axis_socd(0, 1, SocdMode::LastWins), -1.0);
+        assert_eq!(input.axis_socd(0, 1, SocdMode::PositivePriority), 1.0);
+        input.update_val(DPadRight.into(), 0.0);
+        input.update_val(DPadRight.into(), 1.0);
+        assert_eq!(input.axis_socd(0, 1, SocdMode::LastWins), 1.0);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn set_press_sensitivity() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, RightTrigger));
+        input.update_val(RightTrigger.into(), 0.3);
+        assert!(!input.pressing(0));
+        input.init();
+        input.set_press_sensitivity(0.2);
+        assert!(input.pressed(0));
+        input.init();
+        input.set_press_sensitivity(0.5);
+        assert!(input.released(0));
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn bind_groups() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, South, [LeftBumper, North]));
+        input.set_bind_group(0, 1, 5);
+        input.update_val(LeftBumper.into(), 1.0);
+        input.update_val(North.into(), 1.0);
+        assert!(input.pressed(0));
+        input.init();
+        input.set_group_enabled(5, false);
+        assert!(!input.group_enabled(5));
+        assert!(input.released(0));
+        input.update_val(South.into(), 1.0);
+        assert_eq!(input.value(0), 1.0);
+        input.set_group_enabled(5, true);
+        assert_eq!(input.value(0), 2.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn layers() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyE));
+        input.add_layer_binds("gameplay", &crate::binds!((0, Enter), (1, Escape)));
+        input.add_layer_binds("menu", &crate::binds!((2, Enter), (3, Escape)));
+        input.set_layer_enabled("menu", false);
+        assert!(!input.layer_enabled("menu"));
+        assert!(input.layer_enabled(DEFAULT_LAYER));
+
+        input.update_val(Enter.into(), 1.0);
+        assert!(input.pressing(0));
+        assert!(!input.pressing(2));
+
+        input.set_layer_enabled("gameplay", false);
+        input.set_layer_enabled("menu", true);
+        assert!(input.pressing(2));
+        assert!(!input.pressing(0));
+        // an action bound in two turned on layers combines them
+        input.update_val(KeyE.into(), 1.0);
+        input.set_layer_enabled("gameplay", true);
+        assert_eq!(input.value(0), 2.0);
+        input.set_layer_enabled(DEFAULT_LAYER, false);
+        assert_eq!(input.value(0), 1.0);
+
+        assert_eq!(input.layer_binds(DEFAULT_LAYER), crate::binds!((0, KeyE)));
+        let mut menu = input.layer_binds("menu");
+        menu.sort_by_key(|(action, _)| *action);
+        assert_eq!(menu, crate::binds!((2, Enter), (3, Escape)));
+
+        // an action given twice has both of its binds put in the layer
+        let mut input = InputMap::default();
+        input.add_layer_binds("gameplay", &crate::binds!((0, ArrowLeft), (0, ArrowRight)));
+        input.set_layer_enabled("gameplay", false);
+        input.update_val(ArrowRight.into(), 1.0);
+        assert_eq!(input.value(0), 0.0);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn combine_mode() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, South, RightTrigger));
+        input.update_val(South.into(), 1.0);
+        input.update_val(RightTrigger.into(), 0.5);
+        assert_eq!(input.value(0), 1.5);
+        input.set_combine_mode(0, CombineMode::Max);
+        assert_eq!(input.value(0), 1.0);
+        input.update_val(South.into(), 0.0);
+        assert_eq!(input.value(0), 0.5);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn nan_values() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, [LeftTrigger, South]));
+        input.update_val(South.into(), 1.0);
+        input.update_val(LeftTrigger.into(), f32::NAN);
+        assert_eq!(input.value(0), 0.0);
+        input.update_val(LeftTrigger.into(), 0.5);
+        assert_eq!(input.value(0), 0.5);
+        input.update_val(LeftTrigger.into(), f32::INFINITY);
+        assert_eq!(input.value(0), 0.0);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn try_add_binds() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, South));
+        assert!(input.try_add_binds(&crate::binds!((0, [North, East]), (1, South))).is_ok());
+        let duplicate = input.try_add_binds(&crate::binds!((0, West, [East, North])));
+        assert_eq!(duplicate, Err(vec![(0, vec![vec![East.into(), North.into()]])]));
+        assert_eq!(input.try_add_binds(&crate::binds!((1, West, West))), Err(vec![(1, vec![vec![West.into()]])]));
+        assert_eq!(input.bind_values(0).len(), 2);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn sticky() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, LeftBumper));
+        input.set_sticky(0, true);
+        input.update_val(LeftBumper.into(), 1.0);
+        input.init();
+        input.update_val(LeftBumper.into(), 0.0);
+        input.init();
+        assert!(input.pressed(0));
+        assert!(input.consume(0));
+        assert!(!input.pressed(0));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn analog_codes_in_bind() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!(
+            (0, [MouseMoveRight, ShiftLeft]),
+            (1, MouseMoveRight)
+        );
+        input.update_val(MouseMoveRight.into(), 5.0);
+        assert_eq!(input.value(0), 0.0);
+        assert_eq!(input.value(1), 5.0);
+
+        input.update_val(ShiftLeft.into(), 1.0);
+        assert_eq!(input.value(0), 1.0);
+        input.update_val(MouseMoveRight.into(), 0.25);
+        assert_eq!(input.value(0), 0.25);
+        input.update_val(ShiftLeft.into(), 0.0);
+        assert_eq!(input.value(0), 0.0);
+        assert!(input.released(0));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn analog_codes_in_simultaneous_bind() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, [MouseMoveRight, MouseButton::Left]));
+        input.set_simultaneous(0, 0, true);
+        input.update_val(MouseMoveRight.into(), 3.0);
+        input.update_val(MouseButton::Left.into(), 1.0);
+        assert_eq!(input.value(0), 1.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn accumulating() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, MouseScrollUp));
+        let scroll_up = MouseScrollUp.with_id(DeviceId::dummy());
+        input.set_accumulating(MouseScrollUp, true);
+        input.modify_val(scroll_up, |v| v + 1.0);
+        input.init();
+        input.modify_val(scroll_up, |v| v + 2.0);
+        assert_eq!(input.value(0), 3.0);
+        input.reset_accumulated(MouseScrollUp);
+        assert_eq!(input.value(0), 0.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn last_device() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ));
+        let id = DeviceId::dummy();
+        assert_eq!(input.last_device(0), None);
+        input.update_val(DeviceInput::from(KeyZ).with_id(id), 1.0);
+        assert_eq!(input.last_device(0), Some(id));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn rebind() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ, KeyX), (1, Escape, Delete));
+        let id = DeviceId::dummy();
+        let key = |key: KeyCode| DeviceInput::from(key).with_id(id);
+        let (cancel, clear) = ([Escape.into()], [Delete.into()]);
+        input.begin_rebind(0, 0, &cancel, &clear);
+        input.update_val(MouseMove.with_id(id), 1.0);
+        assert_eq!(input.rebinding(), Some((0, 0)));
+        input.update_val(key(KeyC), 1.0);
+        assert_eq!(input.rebinding(), None);
+        // the key doesn't press the action its now bound to until its pressed again
+        assert!(!input.pressing(0));
+        input.update_val(key(KeyC), 0.0);
+        assert!(!input.released(0));
+        input.update_val(key(KeyC), 1.0);
+        assert!(input.pressing(0));
+        input.update_val(key(KeyC), 0.0);
+        assert_eq!(input.actions_for(KeyZ.into()), vec![]);
+        assert_eq!(input.actions_for(KeyC.into()), vec![0]);
+        assert_eq!(input.actions_for(KeyX.into()), vec![0]);
+
+        input.begin_rebind(0, 1, &cancel, &clear);
+        input.update_val(key(Escape), 1.0);
+        assert_eq!(input.rebinding(), None);
+        assert_eq!(input.actions_for(KeyX.into()), vec![0]);
+        // cancelling or clearing doesn't also press whats bound to the key
+        assert!(!input.pressing(1));
+        input.update_val(key(Escape), 0.0);
+        input.begin_rebind(0, 1, &cancel, &clear);
+        input.update_val(key(Delete), 1.0);
+        assert!(!input.pressing(1));
+        input.update_val(key(Delete), 0.0);
+        assert_eq!(input.actions_for(KeyX.into()), vec![]);
+        input.update_val(key(Escape), 1.0);
+        assert!(input.pressing(1));
+        assert_eq!(input.bind_values(0).len(), 2);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn mouse_as_rate() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, MouseMoveRight));
+        input.mouse_as_rate = true;
+        input.mouse_scale = 1.0;
+        let motion = DeviceEvent::MouseMotion { delta: (2.0, 0.0) };
+        input.update_with_device_event(DeviceId::dummy(), &motion);
+        input.update_with_dt(Duration::from_millis(500));
+        assert_eq!(input.value(0), 4.0);
+        input.init();
+        input.update_with_device_event(DeviceId::dummy(), &motion);
+        input.update_with_device_event(DeviceId::dummy(), &motion);
+        input.update_with_dt(Duration::from_secs(1));
+        assert_eq!(input.value(0), 4.0);
+        // calling it again in the same loop spreads the movement over the extra time
+        input.update_with_dt(Duration::from_secs(1));
+        assert_eq!(input.value(0), 2.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn bind_added_while_moving() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, MouseMoveRight));
+        input.mouse_scale = 1.0;
+        let motion = DeviceEvent::MouseMotion { delta: (2.0, 0.0) };
+        input.update_with_device_event(DeviceId::dummy(), &motion);
+        input.add_binds(&crate::binds!((1, MouseMoveRight)));
+        input.update_with_device_event(DeviceId::dummy(), &motion);
+        assert_eq!(input.value(0), 4.0);
+        assert_eq!(input.value(1), 4.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn mouse_as_rate_accumulating() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, MouseMoveRight));
+        input.mouse_as_rate = true;
+        input.mouse_scale = 1.0;
+        input.set_accumulating(MouseMoveRight, true);
+        for _ in 0..2 {
+            input.update_with_device_event(DeviceId::dummy(), &DeviceEvent::MouseMotion { delta: (2.0, 0.0) });
+            input.update_with_dt(Duration::from_millis(500));
+            input.init();
+        }
+        assert_eq!(input.value(0), 4.0);
+    }
+    #[test]
+    #[cfg(all(feature = "mice-keyboard", feature = "gamepad"))]
+    fn history() {
+        let mut input = crate::input_map!((0, base_input_codes::KeyZ));
+        input.set_history_capacity(2);
+
+        input.update_val(base_input_codes::KeyZ.into(), 1.0);
+        assert!(input.was_pressed_ago(0, 0));
+        input.init();
+        assert!(input.was_pressed_ago(0, 1));
+        input.init();
+        assert!(!input.was_pressed_ago(0, 1));
+        assert!(input.was_pressed_ago(0, 2));
+        input.init();
+        assert!(!input.was_pressed_ago(0, 3));
+    }
+    #[test]
+    #[cfg(all(feature = "serde", feature = "gamepad", feature = "mice-keyboard"))]
+    fn serde_round_trip() {
+        use base_input_codes::*;
+        let sorted = |mut binds: Binds<u8>| { binds.sort_by_key(|&(a, _)| a); binds };
+        let mut input = crate::input_map!(
+            (0, KeyW, South, [ControlLeft, 'z']),
+            (1, MouseButton::Left, MouseScrollUp.with_id(DeviceId::dummy()))
+        );
+        let saved = ron::to_string(&input.export_binds()).unwrap();
+        let loaded: Binds<u8> = ron::from_str(&saved).unwrap();
+        input.import_binds(&loaded);
+        assert_eq!(sorted(input.get_binds()), sorted(crate::binds!(
+            (0, KeyW, South, [ControlLeft, 'z']),
+            (1, MouseButton::Left, MouseScrollUp)
+        )));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn action_sensitivity() {
+        use base_input_codes::*;
+        let (trigger, stick) = (DeviceInput::from(KeyZ).with_id(DeviceId::dummy()), DeviceInput::from(KeyX).with_id(DeviceId::dummy()));
+        let mut input = crate::input_map!((0, KeyZ), (1, KeyX));
+        input.set_action_sensitivity(0, 0.1);
+        input.set_action_sensitivity(1, 0.9);
+        input.update_val(trigger, 0.2);
+        input.update_val(stick, 0.6);
+        assert!(input.pressed(0));
+        assert!(!input.pressing(1));
+        // the global sensitivity of 0.5 is still used for `recently_pressed`
+        assert_eq!(input.recently_pressed, Some(stick));
+        input.init();
+        input.clear_action_sensitivity(1);
+        assert!(input.pressed(1));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn remove_action() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, [ControlLeft, KeyZ], KeyX), (1, KeyC, [ShiftLeft, KeyZ]));
+        input.remove_action(0);
+        assert_eq!(input.get_binds(), crate::binds!((1, KeyC, [ShiftLeft, KeyZ])));
+        input.update_val(ShiftLeft.into(), 1.0);
+        input.update_val(KeyZ.into(), 1.0);
+        assert!(input.pressing(1));
+        assert!(!input.pressing(0));
+
+        input.init();
+        input.rebind_action(0, vec![vec![KeyZ.into()]]);
+        assert!(input.pressed(0));
+        input.rebind_action(1, vec![vec![KeyC.into()]]);
+        assert!(input.released(1));
+        assert_eq!(input.bind_values(1).len(), 1);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn stick_deadzone() {
+        use base_input_codes::*;
+        use gilrs::Axis;
+        let mut input = crate::input_map!((0, RightStickRight), (1, RightStickUp), (2, RightStickDown));
+        let id = gamepad_id(0);
+        input.update_gamepad(axis_event(id, Axis::RightStickX, 0.05));
+        input.update_gamepad(axis_event(id, Axis::RightStickY, -0.05));
+        assert_eq!(input.value(0), 0.0);
+        assert_eq!(input.value(2), 0.0);
+        input.set_gamepad_deadzone(id, 0.5);
+        input.update_gamepad(axis_event(id, Axis::RightStickX, 0.0));
+        input.update_gamepad(axis_event(id, Axis::RightStickY, 0.75));
+        assert_eq!(input.value(1), 0.5);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn held_duration() {
+        let mut input = crate::input_map!((0, base_input_codes::KeyZ));
+        assert_eq!(input.held_duration(0), None);
+        input.update_val(base_input_codes::KeyZ.into(), 1.0);
+        input.update_with_dt(Duration::from_millis(300));
+        assert_eq!(input.held_duration(0), Some(Duration::from_millis(300)));
+
+        input.update_val(base_input_codes::KeyZ.into(), 0.0);
+        input.update_val(base_input_codes::KeyZ.into(), 1.0);
+        assert_eq!(input.held_duration(0), Some(Duration::ZERO));
+        input.update_val(base_input_codes::KeyZ.into(), 0.0);
+        assert_eq!(input.held_duration(0), None);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn pressing_actions() {
+        use base_input_codes::*;
+        use std::collections::HashSet;
+        let mut input = crate::input_map!((0, KeyZ), (1, KeyX), (2, KeyC));
+        input.update_val(KeyZ.into(), 1.0);
+        input.update_val(KeyC.into(), 1.0);
+        assert_eq!(input.pressing_actions().collect::<HashSet<_>>(), HashSet::from([0, 2]));
+        input.init();
+        input.update_val(KeyC.into(), 0.0);
+        input.update_val(KeyX.into(), 1.0);
+        assert_eq!(input.pressed_actions().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(input.released_actions().collect::<Vec<_>>(), vec![2]);
+    }
+    #[test]
+    #[cfg(all(feature = "gamepad", feature = "mice-keyboard"))]
+    fn display_names() {
+        use base_input_codes::*;
+        let names = [ShiftLeft.into(), KeyA.into(), Digit1.into(), Numpad1.into(), F5.into(),
+            MouseButton::Left.into(), '/'.into(), DPadUp.into(), South.into()].map(|c: InputCode| c.to_string());
+        assert_eq!(names, ["Left Shift", "A", "1", "Numpad 1", "F5", "Mouse Left", "Char /", "D-Pad Up", "A Button"]);
+        assert_eq!(InputCode::from('?').to_string(), "?");
+        assert_eq!(InputCode::from(GamepadInput::Select).to_string(), "Gamepad Select");
+    }
+    #[test]
+    #[cfg(all(feature = "gamepad", feature = "mice-keyboard"))]
+    fn parse_input_codes() {
+        use base_input_codes::*;
+        assert_eq!("KeyW".parse(), Ok(InputCode::from(KeyW)));
+        assert_eq!("left shift".parse(), Ok(InputCode::from(ShiftLeft)));
+        assert_eq!("MouseLeft".parse(), Ok(InputCode::from(MouseButton::Left)));
+        assert_eq!("GamepadSouth".parse(), Ok(InputCode::from(South)));
+        assert_eq!("GamepadSelect".parse(), Ok(InputCode::from(GamepadInput::Select)));
+        assert_eq!("?".parse(), Ok(InputCode::from('?')));
+        assert_eq!("Jump".parse::<InputCode>(), Err(UnknownInputCode("Jump".to_string())));
+
+        let codes: [InputCode; 11] = [Numpad1.into(), Minus.into(), MouseButton::Other(4).into(), MouseScrollUp.into(),
+            DPadUp.into(), West.into(), 'a'.into(), '/'.into(), '1'.into(), '?'.into(), GamepadInput::Select.into()];
+        for code in codes {
+            assert_eq!(code.to_string().parse(), Ok(code));
+        }
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn all_keys() {
+        use std::collections::HashSet;
+        let keys = crate::input_code::ALL_KEYS;
+        assert_eq!(keys.iter().collect::<HashSet<_>>().len(), keys.len());
+        for key in keys {
+            let code = InputCode::from(key);
+            assert_eq!(code.to_string().parse(), Ok(code));
+            assert_eq!(format!("{key:?}").parse(), Ok(code));
+        }
+    }
+    #[test]
+    #[cfg(all(feature = "gamepad", feature = "mice-keyboard"))]
+    fn triggering_bind() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ, South, [ControlLeft, KeyX]));
+        assert_eq!(input.triggering_bind(0), None);
+        input.update_val(South.into(), 1.0);
+        assert_eq!(input.triggering_bind(0), Some(1));
+        input.update_val(KeyZ.into(), 1.0);
+        assert_eq!(input.triggering_bind(0), Some(0));
+        input.update_val(KeyZ.into(), 0.0);
+        assert_eq!(input.triggering_bind(0), Some(1));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn set_enabled() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, MouseButton::Left), (1, MouseMoveRight));
+        input.set_enabled(false);
+        let click = WindowEvent::MouseInput { device_id: DeviceId::dummy(), state: ElementState::Pressed, button: MouseButton::Left };
+        input.update_with_window_event(&click);
+        input.update_with_window_event(&WindowEvent::Ime(Ime::Commit("a".into())));
+        input.update_with_device_event(DeviceId::dummy(), &DeviceEvent::MouseMotion { delta: (2.0, 0.0) });
+        input.update_with_window_event(&WindowEvent::Resized((300, 200).into()));
+        assert!(!input.enabled());
+        assert_eq!(input.values().filter(|&(_, v)| v != 0.0).count(), 0);
+        assert_eq!((input.recently_pressed, input.text_typed.clone()), (None, None));
+        assert_eq!(input.window_size(), (300.0, 200.0));
+
+        input.set_enabled(true);
+        input.update_with_window_event(&click);
+        assert!(input.pressed(0));
+
+        // a key let go while turned off isn't stuck held, and focus and the cursor are kept up to
+        // date
+        use winit::keyboard::{Key, PhysicalKey, KeyCode};
+        let mut input = crate::input_map!((0, KeyW));
+        let (id, w) = (DeviceId::dummy(), PhysicalKey::Code(KeyCode::KeyW));
+        input.update_key(id, w, &Key::Character("w".into()), Some("w"), true);
+        assert!(input.pressing(0));
+        input.set_enabled(false);
+        input.update_key(id, w, &Key::Character("w".into()), None, false);
+        input.update_with_window_event(&WindowEvent::CursorEntered { device_id: id });
+        input.update_with_window_event(&WindowEvent::Focused(false));
+        input.set_enabled(true);
+        assert!(!input.pressing(0));
+        assert!(input.released(0));
+        assert!(input.cursor_in_window());
+        assert!(!input.focused());
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn smoothing() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyD), (1, KeyA));
+        input.set_smoothing(0, 1.0);
+        input.update_val(KeyD.into(), 1.0);
+        input.update_val(KeyA.into(), 1.0);
+        assert!(input.pressing(0));
+        assert_eq!(input.value(0), 0.0);
+        assert_eq!(input.value(1), 1.0);
+        for expected in [0.25, 0.5, 0.75, 1.0, 1.0] {
+            input.update_with_dt(Duration::from_millis(250));
+            assert_eq!(input.value(0), expected);
+        }
+        input.update_val(KeyD.into(), 0.0);
+        input.update_with_dt(Duration::from_millis(500));
+        assert_eq!(input.value(0), 0.5);
+
+        // the rate is in scaled values, the same as integrating
+        input.update_with_dt(Duration::from_secs(1));
+        input.global_scale = 2.0;
+        input.set_integrating(1, 1.0);
+        input.update_val(KeyD.into(), 1.0);
+        input.update_with_dt(Duration::from_millis(500));
+        assert_eq!(input.value(0), 0.5);
+        assert_eq!(input.integrated(1), 1.0);
+        input.update_with_dt(Duration::from_secs(2));
+        assert_eq!(input.value(0), 2.0);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn bind_scale() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, RightStickUp, DPadUp), (1, RightStickDown, DPadDown));
+        input.set_bind_scale(0, 0, -1.0);
+        input.set_bind_scale(1, 0, -1.0);
+        input.update_val(RightStickUp.into(), 0.5);
+        assert_eq!(input.axis(0, 1), -0.5);
+        assert!(!input.pressing(0));
+
+        input.update_val(RightStickUp.into(), 0.0);
+        input.set_bind_scale(1, 1, 2.0);
+        input.update_val(DPadDown.into(), 1.0);
+        assert_eq!(input.axis(0, 1), -2.0);
+        input.set_bind_scale(1, 1, 1.0);
+        assert_eq!(input.axis(0, 1), -1.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn scroll_delta() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, MouseScrollLeft), (1, MouseScrollRight), (2, MouseScrollDown));
+        let scroll = |x, y| WindowEvent::MouseWheel { device_id: DeviceId::dummy(), delta: MouseScrollDelta::LineDelta(x, y), phase: TouchPhase::Moved };
+        input.update_with_window_event(&scroll(1.0, -2.0));
+        // the signs agree with the scroll input codes
+        assert!(input.pressing(0) && !input.pressing(1) && input.pressing(2));
+        assert_eq!(input.scroll_delta::<(f32, f32)>(), (-1.0, -2.0));
+        input.update_with_window_event(&scroll(-2.5, 0.0));
+        assert!(input.pressing(1));
+        assert_eq!(input.scroll_delta::<(f32, f32)>(), (1.5, -2.0));
+        input.init();
+        assert_eq!(input.scroll_delta::<(f32, f32)>(), (0.0, 0.0));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn mouse_delta() {
+        let mut input = crate::input_map!();
+        input.mouse_scale = 2.0;
+        input.update_with_device_event(DeviceId::dummy(), &DeviceEvent::MouseMotion { delta: (1.0, -2.0) });
+        input.update_with_device_event(DeviceId::dummy(), &DeviceEvent::MouseMotion { delta: (3.0, 0.5) });
+        assert_eq!(input.mouse_delta::<(f32, f32)>(), (8.0, -3.0));
+        input.init();
+        assert_eq!(input.mouse_delta::<(f32, f32)>(), (0.0, 0.0));
+    }
+    #[test]
+    #[cfg(all(feature = "glam", feature = "mint", feature = "mice-keyboard"))]
+    fn vec2_backends() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyD), (1, KeyA), (2, KeyW), (3, KeyS));
+        input.update_val(KeyD.into(), 1.0);
+        assert_eq!(input.dir::<glam::Vec2>(0, 1, 2, 3), glam::Vec2::new(1.0, 0.0));
+        assert_eq!(input.dir::<mint::Vector2<f32>>(0, 1, 2, 3), mint::Vector2 { x: 1.0, y: 0.0 });
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn pressed_edges() {
+        use base_input_codes::*;
+        // a second bind changing while held used to clear pressed
+        let mut input = crate::input_map!((0, KeyZ, KeyX));
+        input.update_val(KeyZ.into(), 1.0);
+        input.update_val(KeyX.into(), 1.0);
+        assert!(input.pressed(0));
+        // and releasing a loop after pressing used to not count
+        let mut input = crate::input_map!((0, KeyZ));
+        input.update_val(KeyZ.into(), 1.0);
+        input.init();
+        input.update_val(KeyZ.into(), 0.0);
+        assert!(input.released(0));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn device_mouse_resets() {
+        use base_input_codes::*;
+        let mut input = InputMap::empty();
+        let mouse = MouseMoveRight.with_id(DeviceId::dummy());
+        input.update_with_device_event(DeviceId::dummy(), &DeviceEvent::MouseMotion { delta: (1.0, 0.0) });
+        assert!(input.code_value(mouse) > 0.0);
+        input.init();
+        assert_eq!(input.code_value(mouse), 0.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn global_scale() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ), (1, KeyX), (2, KeyC, KeyV));
+        input.global_scale = 2.0;
+        input.set_integrating(1, 1.0);
+        input.set_turbo(0, 4.0);
+        input.set_min_binds(2, 2);
+        // 0.3 is under the sensitivity of 0.5 but scaled its 0.6
+        input.update_val(KeyZ.into(), 0.3);
+        assert!(input.pressed(0));
+        assert_eq!(input.value(0), 0.6);
+
+        input.update_val(KeyX.into(), 0.3);
+        input.update_with_dt(Duration::from_secs(1));
+        assert_eq!(input.integrated(1), 0.6);
+
+        input.init();
+        input.update_with_dt(Duration::from_millis(125));
+        input.init();
+        input.update_with_dt(Duration::from_millis(125));
+        assert!(input.pressed(0));
+
+        input.update_val(KeyC.into(), 0.3);
+        assert!(!input.pressing(2));
+        input.update_val(KeyV.into(), 0.3);
+        assert!(input.pressed(2));
+    }
+    #[test]
+    #[cfg(all(feature = "gamepad", feature = "mice-keyboard"))]
+    fn active_input_kind() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ));
+        let key = DeviceInput::from(KeyZ).with_id(DeviceId::dummy());
+        input.update_val(key, 1.0);
+        assert_eq!(input.active_input_kind(), InputKind::MouseKeyboard);
+        input.update_gamepad(axis_event(gamepad_id(0), gilrs::Axis::LeftStickX, 1.0));
+        assert_eq!(input.active_input_kind(), InputKind::Gamepad);
+        // letting go of a key isn't using the keyboard
+        input.update_val(key, 0.0);
+        assert_eq!(input.active_input_kind(), InputKind::Gamepad);
+        input.update_val(key, 1.0);
+        assert_eq!(input.active_input_kind(), InputKind::MouseKeyboard);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn get_binds_multi_code() {
+        use base_input_codes::*;
+        let sorted = |mut binds: Binds<u8>| { binds.sort_by_key(|&(a, _)| a); binds };
+        // the bind hash gives codes back in any order, so later codes in a bind are often seen
+        // before earlier ones. with this many binds at least one is out of order
+        let binds = crate::binds!(
+            (0, [KeyA, KeyB, KeyC, KeyD], [KeyE, KeyF, KeyG, KeyH]),
+            (1, [KeyI, KeyJ, KeyK, KeyL], [KeyM, KeyN, KeyO, KeyP]),
+            (2, [KeyQ, KeyR, KeyS, KeyT], [KeyU, KeyV, KeyW, KeyX])
+        );
+        assert_eq!(sorted(InputMap::new(&binds).get_binds()), binds);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn mouse_pos() {
+        let mut input = crate::input_map!();
+        input.set_window_size((200.0, 100.0));
+        input.set_scale_factor(2.0);
+        input.update_with_window_event(&WindowEvent::CursorMoved { device_id: DeviceId::dummy(), position: (50.0, 20.0).into() });
+        assert_eq!(input.mouse_pos::<(f32, f32)>(), (50.0, 20.0));
+        assert_eq!(input.mouse_pos::<[f32; 2]>(), [50.0, 20.0]);
+        assert_eq!(input.mouse_pos_normalized::<(f32, f32)>(), (0.25, 0.2));
+        assert_eq!(input.mouse_pos_logical::<(f32, f32)>(), (25.0, 10.0));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn mouse_moved() {
+        let mut input = crate::input_map!();
+        assert!(!input.mouse_moved());
+        input.update_with_device_event(DeviceId::dummy(), &DeviceEvent::MouseMotion { delta: (1.0, 0.0) });
+        assert!(input.mouse_moved());
+        input.init();
+        assert!(!input.mouse_moved());
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn stick_moved() {
+        use gilrs::Axis;
+        let mut input = crate::input_map!();
+        let id = gamepad_id(0);
+        // inside the deadzone of 0.1
+        input.update_gamepad(axis_event(id, Axis::LeftStickX, 0.05));
+        assert!(!input.left_stick_moved(SpecifyGamepad::Any));
+        input.update_gamepad(axis_event(id, Axis::LeftStickY, 0.5));
+        assert!(input.left_stick_moved(SpecifyGamepad::Id(id)));
+        assert!(input.left_stick_moved(SpecifyGamepad::Any));
+        assert!(!input.right_stick_moved(SpecifyGamepad::Any));
+        input.update_gamepad(axis_event(id, Axis::RightStickX, -0.5));
+        assert!(input.right_stick_moved(SpecifyGamepad::Id(id)));
+        assert!(!input.right_stick_moved(SpecifyGamepad::Id(gamepad_id(1))));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn time_since_pressed() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ));
+        assert_eq!(input.time_since_pressed(0), None);
+        input.update_val(KeyZ.into(), 1.0);
+        input.update_with_dt(Duration::from_millis(300));
+        assert_eq!(input.time_since_pressed(0), Some(Duration::from_millis(300)));
+        // it keeps counting after being released
+        input.update_val(KeyZ.into(), 0.0);
+        input.update_with_dt(Duration::from_millis(200));
+        assert_eq!(input.time_since_pressed(0), Some(Duration::from_millis(500)));
+        input.update_val(KeyZ.into(), 1.0);
+        assert_eq!(input.time_since_pressed(0), Some(Duration::ZERO));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn is_up_and_down() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ));
+        assert!(input.is_up(0) && !input.is_down(0));
+        input.update_val(KeyZ.into(), 1.0);
+        input.init();
+        // unlike pressed it stays true on later loops
+        assert!(input.is_down(0) && !input.is_up(0));
+        input.update_val(KeyZ.into(), 0.0);
+        input.init();
+        assert!(input.is_up(0) && !input.is_down(0));
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn gamepad_kind() {
+        assert_eq!(GamepadKind::detect(Some(0x054c), "Wireless Controller"), GamepadKind::PlayStation);
+        assert_eq!(GamepadKind::detect(Some(0x045e), "Controller"), GamepadKind::Xbox);
+        // the vendor wins over the name
+        assert_eq!(GamepadKind::detect(Some(0x057e), "Xbox Style Pad"), GamepadKind::Nintendo);
+        assert_eq!(GamepadKind::detect(None, "Sony DualSense"), GamepadKind::PlayStation);
+        assert_eq!(GamepadKind::detect(Some(0x1234), "XInput Controller"), GamepadKind::Xbox);
+        assert_eq!(GamepadKind::detect(None, "Nintendo Switch Pro Controller"), GamepadKind::Nintendo);
+        assert_eq!(GamepadKind::detect(None, "Generic USB Joystick"), GamepadKind::Generic);
+        assert_eq!(InputMap::empty().gamepad_kind(gamepad_id(0)), GamepadKind::Generic);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn button_remap() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, South), (1, East));
+        // a controller with its face buttons swapped
+        input.button_remap.insert(gilrs::Button::South, East);
+        input.update_gamepad(button_event(gamepad_id(0), gilrs::Button::South, 1.0));
+        assert!(!input.pressing(0));
+        assert!(input.pressing(1));
+        input.update_gamepad(button_event(gamepad_id(0), gilrs::Button::South, 0.0));
+        // buttons that aren't remapped are read as usual
+        input.button_remap.clear();
+        input.update_gamepad(button_event(gamepad_id(0), gilrs::Button::South, 1.0));
+        assert!(input.pressing(0));
+        assert!(!input.pressing(1));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn profiles() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ));
+        let mut profiles = crate::Profiles::new();
+        profiles.add("a", crate::binds!((0, KeyA)));
+        profiles.add("b", crate::binds!((0, KeyB)));
+        profiles.add("c", crate::binds!((0, KeyC)));
+        assert!(profiles.apply("c", &mut input));
+        assert_eq!(input.get_binds(), crate::binds!((0, KeyC)));
+        assert!(!profiles.apply("d", &mut input));
+        // removing an earlier profile keeps the same profile current
+        assert_eq!(profiles.remove("a"), Some(crate::binds!((0, KeyA))));
+        assert_eq!(profiles.current(), Some("c"));
+        assert!(profiles.rename("c", "C"));
+        assert_eq!(profiles.current(), Some("C"));
+        assert!(profiles.rename("C", "C"));
+        assert!(!profiles.rename("C", "b"));
+        assert!(!profiles.rename("a", "A"));
+        assert_eq!(profiles.names().collect::<Vec<_>>(), ["b", "C"]);
+        // removing a later profile doesn't change the current one either
+        profiles.apply("b", &mut input);
+        profiles.remove("C");
+        assert_eq!(profiles.current(), Some("b"));
+        profiles.remove("b");
+        assert_eq!(profiles.current(), None);
+        assert_eq!(profiles.get("b"), None);
+    }
+    #[test]
+    #[cfg(all(feature = "serde", feature = "mice-keyboard"))]
+    fn profiles_serde() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ));
+        let mut profiles = crate::Profiles::new();
+        profiles.add("a", crate::binds!((0, KeyA)));
+        profiles.add("b", crate::binds!((0, KeyB, [ControlLeft, KeyC])));
+        profiles.apply("b", &mut input);
+
+        let saved = ron::to_string(&profiles).unwrap();
+        let loaded: crate::Profiles<i32> = ron::from_str(&saved).unwrap();
+        assert_eq!(loaded.current(), Some("b"));
+        assert_eq!(loaded.get("b"), profiles.get("b"));
+        assert_eq!(loaded.names().collect::<Vec<_>>(), ["a", "b"]);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn char_binds() {
+        use base_input_codes::*;
+        use winit::keyboard::{Key, PhysicalKey, KeyCode};
+        let mut input = crate::input_map!((0, DeviceInput::Char('a')), (1, KeyA));
+        let (id, q) = (DeviceId::dummy(), PhysicalKey::Code(KeyCode::KeyQ));
+        // azerty keyboards type an a with the key thats q on qwerty
+        input.update_key(id, q, &Key::Character("a".into()), Some("a"), true);
+        assert!(input.pressing(0));
+        assert!(!input.pressing(1));
+        // still released when shift changes what the key types
+        input.update_key(id, q, &Key::Character("A".into()), None, false);
+        assert!(!input.pressing(0));
+        // typing an uppercase letter presses the lowercase one
+        input.update_key(id, q, &Key::Character("A".into()), Some("A"), true);
+        assert!(input.pressing(0));
+        // keys that type more than one character or none don't press a char
+        let mut input = crate::input_map!((0, DeviceInput::Char('a')));
+        input.update_key(id, q, &Key::Character("aa".into()), Some("aa"), true);
+        input.update_key(id, PhysicalKey::Code(KeyCode::Enter), &Key::Named(winit::keyboard::NamedKey::Enter), None, true);
+        assert!(!input.pressing(0));
+    }
+    #[test]
+    #[cfg(all(feature = "gamepad", feature = "mice-keyboard"))]
+    fn accept_input_releases() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, MouseButton::Left), (1, South), (2, LeftStickRight));
+        let (id, gamepad) = (DeviceId::dummy(), gamepad_id(0));
+        let click = |state| WindowEvent::MouseInput { device_id: id, state, button: MouseButton::Left };
+        input.update_with_window_event(&click(ElementState::Pressed));
+        input.update_gamepad(button_event(gamepad, gilrs::Button::South, 1.0));
+        input.update_gamepad(axis_event(gamepad, gilrs::Axis::LeftStickX, 1.0));
+        input.accept_input = false;
+        // presses are ignored but releases still go through
+        input.update_gamepad(button_event(gamepad, gilrs::Button::East, 1.0));
+        input.update_with_window_event(&click(ElementState::Released));
+        input.update_gamepad(button_event(gamepad, gilrs::Button::South, 0.0));
+        input.update_gamepad(axis_event(gamepad, gilrs::Axis::LeftStickX, 0.0));
+        input.update_with_window_event(&WindowEvent::CursorLeft { device_id: id });
+        input.accept_input = true;
+        assert_eq!(input.pressing_actions().count(), 0);
+        assert_eq!(input.code_value(East.into()), 0.0);
+        assert!(!input.cursor_in_window());
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn mouse_acceleration() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, MouseMoveRight));
+        let id = DeviceId::dummy();
+        let moved = |input: &mut InputMap<i32>, x| {
+            input.update_with_device_event(id, &DeviceEvent::MouseMotion { delta: (x, 0.0) });
+            let v = input.value(0);
+            input.init();
+            v
+        };
+        input.mouse_scale = 1.0;
+        assert_eq!(moved(&mut input, 2.0), 2.0);
+        input.mouse_acceleration = 0.5;
+        // 2 pixels is multiplied by 1 + 0.5 * 2
+        assert_eq!(moved(&mut input, 2.0), 4.0);
+        // and 10 pixels would be 6 times but its capped by mouse_acceleration_max
+        input.mouse_acceleration_max = 2.0;
+        assert_eq!(moved(&mut input, 10.0), 20.0);
+        // a max under 1 never slows the mouse down
+        input.mouse_acceleration_max = 0.5;
+        assert_eq!(moved(&mut input, 2.0), 2.0);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn button_pressure() {
+        use base_input_codes::*;
+        let mut input = InputMap::empty();
+        let (a, b) = (gamepad_id(0), gamepad_id(1));
+        input.update_gamepad(button_event(a, gilrs::Button::RightTrigger2, 0.25));
+        input.update_gamepad(button_event(b, gilrs::Button::RightTrigger2, 0.75));
+        assert_eq!(input.button_pressure(RightTrigger, SpecifyGamepad::Id(a)), 0.25);
+        assert_eq!(input.button_pressure(RightTrigger, SpecifyGamepad::Id(b)), 0.75);
+        assert_eq!(input.button_pressure(RightTrigger, SpecifyGamepad::Any), 0.75);
+        assert_eq!(input.button_pressure(South, SpecifyGamepad::Any), 0.0);
+        input.update_gamepad(button_event(b, gilrs::Button::RightTrigger2, 0.0));
+        assert_eq!(input.button_pressure(RightTrigger, SpecifyGamepad::Any), 0.25);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn printable_text() {
+        use winit::keyboard::{Key, PhysicalKey, KeyCode, NamedKey};
+        let mut input = InputMap::empty();
+        let id = DeviceId::dummy();
+        assert_eq!(input.printable_text(), None);
+        input.update_key(id, PhysicalKey::Code(KeyCode::KeyH), &Key::Character("h".into()), Some("h"), true);
+        input.update_key(id, PhysicalKey::Code(KeyCode::Backspace), &Key::Named(NamedKey::Backspace), Some("\u{8}"), true);
+        input.update_key(id, PhysicalKey::Code(KeyCode::KeyI), &Key::Character("i".into()), Some("i"), true);
+        input.update_key(id, PhysicalKey::Code(KeyCode::Enter), &Key::Named(NamedKey::Enter), Some("\r"), true);
+        assert_eq!(input.printable_text().as_deref(), Some("hi"));
+        assert_eq!(input.text_typed.as_deref(), Some("h\u{8}i\r"));
+        // only control characters is the same as nothing typed
+        input.init();
+        input.update_key(id, PhysicalKey::Code(KeyCode::Tab), &Key::Named(NamedKey::Tab), Some("\t"), true);
+        assert_eq!(input.printable_text(), None);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn any_button() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, AnyButton));
+        let (a, b) = (gamepad_id(0), gamepad_id(1));
+        input.update_gamepad(button_event(a, gilrs::Button::South, 1.0));
+        assert!(input.pressing(0));
+        input.update_gamepad(button_event(b, gilrs::Button::North, 1.0));
+        // one gamepad letting go or disconnecting doesn't release it for the other
+        input.update_gamepad(button_event(a, gilrs::Button::South, 0.0));
+        assert!(input.pressing(0));
+        input.update_gamepad(button_event(a, gilrs::Button::East, 1.0));
+        input.update_gamepad(gilrs::Event::new(b, gilrs::EventType::Disconnected));
+        assert!(input.pressing(0));
+        assert_eq!(input.code_value(AnyButton.with_id(a)), 1.0);
+        assert_eq!(input.code_value(AnyButton.with_id(b)), 0.0);
+        input.update_gamepad(button_event(a, gilrs::Button::East, 0.0));
+        assert!(!input.pressing(0));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn any_key() {
+        use base_input_codes::*;
+        use winit::keyboard::{Key, PhysicalKey, KeyCode};
+        let mut input = crate::input_map!((0, AnyKey));
+        let id = DeviceId::dummy();
+        let key = |input: &mut InputMap<i32>, code, pressed| {
+            input.update_key(id, PhysicalKey::Code(code), &Key::Dead(None), None, pressed)
+        };
+        key(&mut input, KeyCode::KeyQ, true);
+        assert!(input.pressed(0));
+        key(&mut input, KeyCode::F7, true);
+        // still held while any key is
+        key(&mut input, KeyCode::KeyQ, false);
+        assert!(input.pressing(0));
+        assert_eq!(input.code_value(AnyKey.with_id(id)), 1.0);
+        key(&mut input, KeyCode::F7, false);
+        assert!(!input.pressing(0));
+        // the real key is recently_pressed rather than AnyKey
+        key(&mut input, KeyCode::Space, true);
+        assert_eq!(input.recently_pressed, Some(DeviceInput::from(KeyCode::Space).with_id(id)));
+    }
+    #[test]
+    #[cfg(all(feature = "gamepad", feature = "mice-keyboard"))]
+    fn focus() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, MouseMoveRight), (1, LeftStickRight), (2, South));
+        let (id, gamepad) = (DeviceId::dummy(), gamepad_id(0));
+        let motion = DeviceEvent::MouseMotion { delta: (5.0, 0.0) };
+        input.update_gamepad(axis_event(gamepad, gilrs::Axis::LeftStickX, 1.0));
+        input.update_gamepad(button_event(gamepad, gilrs::Button::South, 1.0));
+        input.update_with_window_event(&WindowEvent::Focused(false));
+        assert!(!input.focused());
+        assert_eq!(input.pressing_actions().count(), 0);
+        // the raw stick and button values are reset with the binds
+        assert_eq!(input.left_stick::<(f32, f32)>(SpecifyGamepad::Any), (0.0, 0.0));
+        assert_eq!(input.button_pressure(South, SpecifyGamepad::Any), 0.0);
+        input.update_gamepad(axis_event(gamepad, gilrs::Axis::LeftStickY, 0.5));
+        assert_eq!(input.raw_left_stick::<(f32, f32)>(SpecifyGamepad::Id(gamepad)), (0.0, 0.5));
+
+        // device events are ignored while unfocused
+        input.update_with_device_event(id, &motion);
+        assert_eq!(input.value(0), 0.0);
+        input.set_focus(true);
+        input.init();
+        input.update_with_device_event(id, &motion);
+        assert!(input.value(0) > 0.0);
+        // and while input is turned off
+        input.init();
+        input.accept_input = false;
+        input.update_with_device_event(id, &motion);
+        assert_eq!(input.value(0), 0.0);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn axis_deadzone() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, RightTrigger), (1, LeftTrigger));
+        input.update_val(RightTrigger.into(), 0.25);
+        assert_eq!(input.axis_deadzone(0, 1, 0.5), 0.0);
+        // rescaled to start from 0 at the edge of the deadzone
+        input.update_val(RightTrigger.into(), 0.75);
+        assert_eq!(input.axis_deadzone(0, 1, 0.5), 0.5);
+        input.update_val(RightTrigger.into(), 0.0);
+        input.update_val(LeftTrigger.into(), 0.75);
+        assert_eq!(input.axis_deadzone(0, 1, 0.5), -0.5);
+        // clamped even when the axis goes past 1
+        input.global_scale = 4.0;
+        assert_eq!(input.axis_deadzone(0, 1, 0.5), -1.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn clamp_all_to_unit() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, KeyZ, KeyX), (1, KeyC));
+        input.set_bind_scale(1, 0, -1.0);
+        input.update_val(KeyZ.into(), 1.0);
+        input.update_val(KeyX.into(), 1.0);
+        input.update_val(KeyC.into(), 1.0);
+        input.set_smoothing(0, 1000.0);
+        input.update_with_dt(Duration::from_secs(1));
+        assert_eq!((input.value(0), input.value(1)), (2.0, -1.0));
+        input.clamp_all_to_unit(true);
+        // smoothed values are clamped too
+        assert_eq!((input.value(0), input.value(1)), (1.0, 0.0));
+        assert_eq!(input.axis(0, 1), 1.0);
+        input.clamp_all_to_unit(false);
+        assert_eq!(input.value(0), 2.0);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn release_info() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, RightTrigger));
+        input.update_val(RightTrigger.into(), 0.5);
+        input.update_with_dt(Duration::from_millis(100));
+        input.init();
+        input.update_val(RightTrigger.into(), 0.9);
+        input.update_val(RightTrigger.into(), 0.7);
+        input.update_with_dt(Duration::from_millis(150));
+        assert_eq!(input.release_info(0), None);
+        input.init();
+        input.update_val(RightTrigger.into(), 0.0);
+        assert_eq!(input.release_info(0), Some((Duration::from_millis(250), 0.9)));
+        input.init();
+        assert_eq!(input.release_info(0), None);
+    }
+    #[test]
+    #[cfg(feature = "gamepad")]
+    fn set_gamepad_enabled() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, South), (1, LeftStickRight));
+        let (a, b) = (gamepad_id(0), gamepad_id(1));
+        input.update_gamepad(button_event(a, gilrs::Button::South, 1.0));
+        input.update_gamepad(button_event(b, gilrs::Button::South, 1.0));
+        input.update_gamepad(axis_event(a, gilrs::Axis::LeftStickX, 1.0));
+        input.update_gamepad(axis_event(b, gilrs::Axis::LeftStickX, 0.75));
+        input.set_gamepad_enabled(a, false);
+        // the other gamepad is still holding them
+        assert!(input.pressing(0));
+        assert_eq!(input.value(1), input.code_value(LeftStickRight.with_id(b)));
+        assert!(input.value(1) > 0.0);
+        assert_eq!(input.code_value(South.with_id(a)), 0.0);
+        assert_eq!(input.left_stick::<(f32, f32)>(SpecifyGamepad::Id(a)), (0.0, 0.0));
+        // events from the disabled gamepad are ignored
+        input.update_gamepad(button_event(a, gilrs::Button::East, 1.0));
+        assert_eq!(input.code_value(East.into()), 0.0);
+        input.set_gamepad_enabled(b, false);
+        assert_eq!(input.pressing_actions().count(), 0);
+        input.set_gamepad_enabled(a, true);
+        input.update_gamepad(button_event(a, gilrs::Button::South, 1.0));
+        assert!(input.pressing(0));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn dir_clamped_deadzone() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, MouseMoveRight), (1, MouseMoveLeft), (2, MouseMoveDown), (3, MouseMoveUp));
+        let mut dir = |x: f32, y: f32| {
+            input.update_val(MouseMoveRight.into(), x.max(0.0));
+            input.update_val(MouseMoveLeft.into(), (-x).max(0.0));
+            input.update_val(MouseMoveDown.into(), y.max(0.0));
+            input.update_val(MouseMoveUp.into(), (-y).max(0.0));
+            let (x, y): (f32, f32) = input.dir_clamped_deadzone(0, 1, 2, 3, 0.5);
+            ((x * 1000.0).round() / 1000.0, (y * 1000.0).round() / 1000.0)
+        };
+        assert_eq!(dir(0.3, 0.4), (0.0, 0.0));
+        // halfway from the deadzone to 1 is half as long
+        assert_eq!(dir(0.45, -0.6), (0.3, -0.4));
+        assert_eq!(dir(-0.6, 0.8), (-0.6, 0.8));
+        // and its never longer than 1
+        assert_eq!(dir(3.0, 4.0), (0.6, 0.8));
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn focus_mouse_delay() {
+        use base_input_codes::*;
+        let mut input = crate::input_map!((0, MouseMoveRight));
+        let (id, motion) = (DeviceId::dummy(), DeviceEvent::MouseMotion { delta: (5.0, 0.0) });
+        // without a delay only the loop focus is gained on is ignored
+        input.set_focus(false);
+        input.set_focus(true);
+        input.update_with_device_event(id, &motion);
+        assert_eq!(input.value(0), 0.0);
+        input.init();
+        input.update_with_device_event(id, &motion);
+        assert!(input.value(0) > 0.0);
+
+        input.focus_mouse_delay = Duration::from_millis(100);
+        input.set_focus(false);
+        input.set_focus(true);
+        input.update_with_dt(Duration::from_millis(60));
+        input.init();
+        input.update_with_device_event(id, &motion);
+        assert_eq!(input.value(0), 0.0);
+        // other inputs aren't delayed
+        input.update_with_device_event(id, &DeviceEvent::MouseWheel { delta: MouseScrollDelta::LineDelta(0.0, 1.0) });
+        assert!(input.code_value(MouseScrollUp.into()) > 0.0);
+        input.update_with_dt(Duration::from_millis(60));
+        input.init();
+        input.update_with_device_event(id, &motion);
+        assert!(input.value(0) > 0.0);
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn binds_with_prefix() {
+        use base_input_codes::*;
+        let input = crate::input_map!(
+            (0, [ControlLeft, KeyS], KeyF),
+            (1, [ControlLeft, ShiftLeft, KeyS]),
+            (2, [ShiftLeft, KeyZ], ControlLeft)
+        );
+        let mut binds = input.binds_with_prefix(&[ControlLeft.into()]);
+        binds.sort_by_key(|(action, _)| *action);
+        assert_eq!(binds, vec![
+            (0, vec![ControlLeft.into(), KeyS.into()]),
+            (1, vec![ControlLeft.into(), ShiftLeft.into(), KeyS.into()])
+        ]);
+        // the held codes can be in any order but a bind of only the held codes isn't included
+        let binds = input.binds_with_prefix(&[ShiftLeft.into(), ControlLeft.into()]);
+        assert_eq!(binds, vec![(1, vec![ControlLeft.into(), ShiftLeft.into(), KeyS.into()])]);
+        assert!(input.binds_with_prefix(&[KeyF.into()]).is_empty());
+    }
+    #[test]
+    #[cfg(feature = "mice-keyboard")]
+    fn digit_row_and_function_keys() {
+        use base_input_codes::*;
+        let digits = crate::digit_row();
+        assert_eq!((digits[0], digits[8], digits[9]), (Digit1.into(), Digit9.into(), Digit0.into()));
+        let keys = crate::function_keys();
+        assert_eq!((keys[0], keys[11]), (F1.into(), F12.into()));
+
+        let binds = digits.into_iter().enumerate().map(|(slot, key)| (slot, vec![vec![key]])).collect();
+        let mut input = InputMap::new(&binds);
+        input.update_val(Digit3.into(), 1.0);
+        input.update_val(Digit0.into(), 1.0);
+        assert_eq!(input.pressing_actions().collect::<std::collections::BTreeSet<_>>(), [2, 9].into());
+    }
+}
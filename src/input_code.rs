@@ -1,279 +1,705 @@
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-/// Enum that specifies an input
-pub enum InputCode {
-    #[cfg(feature = "mice-keyboard")]
-    Device { id: SpecifyDevice, input: DeviceInput },
-    #[cfg(feature = "gamepad")]
-    Gamepad { id: SpecifyGamepad, input: GamepadInput }
-}
-impl InputCode {
-    /// sets `SpecifyGamepad` or `SpecifyDevice` to any
-    pub fn set_any(self) -> Self {
-        match self {
-            #[cfg(feature = "gamepad")]
-            Self::Gamepad { input, .. } => input.into(),
-            #[cfg(feature = "mice-keyboard")]
-            Self::Device  { input, .. } => input.into(),
-        }
-    }
-    pub fn is_any(self) -> bool {
-        match self {
-            #[cfg(feature = "gamepad")]
-            Self::Gamepad { id, .. } => id == SpecifyGamepad::Any,
-            #[cfg(feature = "mice-keyboard")]
-            Self::Device  { id, .. } => id == SpecifyDevice::Any,
-        }
-    }
-    #[cfg(feature = "mice-keyboard")]
-    pub fn has_device_id(&self, id: winit::event::DeviceId) -> bool {
-        match self {
-            Self::Device { id: SpecifyDevice::Id(cid), .. } => *cid == id,
-            Self::Device { id: SpecifyDevice::Any,     .. } => true,
-            _ => false
-        }
-    }
-    #[cfg(feature = "gamepad")]
-    pub fn has_gamepad_id(&self, id: gilrs::GamepadId) -> bool {
-        match self {
-            Self::Gamepad { id: SpecifyGamepad::Id(cid), .. } => *cid == id,
-            Self::Gamepad { id: SpecifyGamepad::Any,     .. } => true,
-            _ => false
-        }
-    }
-    #[cfg(feature = "gamepad")]
-    #[allow(irrefutable_let_patterns)]
-    /// sets the gamepad id. if its a device it does nothing.
-    pub fn set_gamepad_id(self, id: gilrs::GamepadId) -> Self {
-        if let Self::Gamepad { input, .. } = self { input.with_id(id) }
-        else { self }
-    }
-    #[cfg(feature = "mice-keyboard")]
-    #[allow(irrefutable_let_patterns)]
-    /// sets the device id. if its a gamepad it does nothing.
-    pub fn set_device_id(self, id: winit::event::DeviceId) -> Self {
-        if let Self::Device { input, .. } = self { input.with_id(id) }
-        else { self }
-    }
-}
-/// imports everything needed to reduce boilerplate when creating an input_map
-pub mod base_input_codes {
-    #![allow(ambiguous_glob_reexports)]
-    use crate::input_code::*;
-
-    #[cfg(feature = "gamepad")]
-    pub use gamepad::GamepadInput::{*, self};
-
-    #[cfg(feature = "mice-keyboard")]
-    pub use mice_keyboard::DeviceInput::{*, self};
-
-    #[cfg(feature = "mice-keyboard")]
-    pub use winit::{
-        keyboard::{KeyCode::{*, self}, PhysicalKey::{*, self}},
-        event::MouseButton
-    };
-}
-
-#[cfg(feature = "mice-keyboard")]
-pub use mice_keyboard::*;
-#[cfg(feature = "mice-keyboard")]
-mod mice_keyboard {
-    use winit::keyboard::{ KeyCode, PhysicalKey };
-    use winit::event::*;
-    use crate::InputCode;
-    #[cfg(feature = "mice-keyboard")]
-    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-    pub enum DeviceInput {
-        Button(MouseButton),
-        Key(PhysicalKey),
-        MouseMoveLeft,
-        MouseMoveRight,
-        MouseMoveUp,
-        MouseMoveDown,
-        MouseScrollUp,
-        MouseScrollDown,
-        MouseScrollLeft,
-        MouseScrollRight,
-    }
-    #[cfg(feature = "mice-keyboard")]
-    impl DeviceInput {
-        pub fn with_id(self, id: DeviceId) -> InputCode {
-            InputCode::Device { id: SpecifyDevice::Id(id), input: self }
-        }
-        pub fn with_sid(self, id: SpecifyDevice) -> InputCode {
-            InputCode::Device { id, input: self }
-        }
-    }
-    #[cfg(feature = "mice-keyboard")]
-    impl From<MouseButton> for DeviceInput {
-        fn from(value: MouseButton) -> Self {
-            Self::Button(value) 
-        }
-    }
-    #[cfg(feature = "mice-keyboard")]
-    impl From<KeyCode> for DeviceInput {
-        fn from(value: KeyCode) -> Self {
-            Self::Key(value.into()) 
-        }
-    }
-    #[cfg(feature = "mice-keyboard")]
-    impl From<PhysicalKey> for DeviceInput {
-        fn from(value: PhysicalKey) -> Self {
-            Self::Key(value)
-        }
-    }
-    /// specify device to listen to. defaults to any and can be specified later on at runtime
-    #[cfg(feature = "mice-keyboard")]
-    #[derive(Default, Debug, PartialEq, Eq, Clone, Copy, Hash)]
-    pub enum SpecifyDevice {
-        /// cant be set at compile time. use `Any` as default and then let the user select a specific
-        /// gamepad at runtime
-        Id(DeviceId),
-        /// use as default
-        #[default]
-        Any
-    }
-    impl From<DeviceInput> for InputCode {
-        fn from(value: DeviceInput) -> Self {
-            Self::Device { id: SpecifyDevice::Any, input: value }
-        }
-    }
-    impl From<MouseButton> for InputCode {
-        fn from(value: MouseButton) -> Self {
-            Self::Device { id: SpecifyDevice::Any, input: value.into() }
-        }
-    }
-    impl From<PhysicalKey> for InputCode {
-        fn from(value: PhysicalKey) -> Self {
-            Self::Device { id: SpecifyDevice::Any, input: value.into() }
-        }
-    }
-    impl From<KeyCode> for InputCode {
-        fn from(value: KeyCode) -> Self {
-            Self::Device { id: SpecifyDevice::Any, input: value.into() }
-        }
-    }
-}
-
-
-#[cfg(feature = "gamepad")]
-pub use gamepad::*;
-#[cfg(feature = "gamepad")]
-mod gamepad {
-    use crate::InputCode;
-    use gilrs::{Axis, Button};
-    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-    pub enum GamepadInput {
-        LeftStickLeft,
-        /// the left stick, moved to the right
-        LeftStickRight,
-        LeftStickUp,
-        LeftStickDown,
-        LeftStickPress,
-
-        /// the right stick, moved to the left
-        RightStickLeft,
-        RightStickRight,
-        RightStickUp,
-        RightStickDown,
-        RightStickPress,
-
-        DPadLeft,
-        DPadRight,
-        DPadUp,
-        DPadDown,
-
-        LeftZ,
-        RightZ,
-
-        South,
-        East,
-        North,
-        West,
-
-        LeftBumper,
-        LeftTrigger,
-        RightBumper,
-        RightTrigger,
-
-        Select,
-        Start,
-        Mode,
-        /// unfortunately gilrs doesnt give enough infomation to have multiple 'Other' input binds
-        Other
-    }
-    impl GamepadInput {
-        pub fn with_id(self, id: gilrs::GamepadId) -> InputCode {
-            InputCode::Gamepad { id: SpecifyGamepad::Id(id), input: self }
-        }
-        pub fn with_sid(self, id: SpecifyGamepad) -> InputCode {
-            InputCode::Gamepad { id, input: self }
-        }
-    }
-    pub fn axis_neg(axis: Axis) -> GamepadInput {
-        match axis {
-            Axis::LeftStickX => GamepadInput::LeftStickLeft,
-            Axis::LeftStickY => GamepadInput::LeftStickDown,
-            Axis::RightStickX => GamepadInput::RightStickLeft,
-            Axis::RightStickY => GamepadInput::RightStickDown,
-            Axis::LeftZ => GamepadInput::LeftZ,
-            Axis::RightZ => GamepadInput::RightZ,
-            Axis::DPadX => GamepadInput::DPadLeft,
-            Axis::DPadY => GamepadInput::DPadDown,
-            Axis::Unknown => GamepadInput::Other
-        }
-    }
-    pub fn axis_pos(axis: Axis) -> GamepadInput {
-        match axis {
-            Axis::LeftStickX => GamepadInput::LeftStickRight,
-            Axis::LeftStickY => GamepadInput::LeftStickUp,
-            Axis::RightStickX => GamepadInput::RightStickRight,
-            Axis::RightStickY => GamepadInput::RightStickUp,
-            Axis::LeftZ => GamepadInput::LeftZ,
-            Axis::RightZ => GamepadInput::RightZ,
-            Axis::DPadX => GamepadInput::DPadRight,
-            Axis::DPadY => GamepadInput::DPadUp,
-            Axis::Unknown => GamepadInput::Other,
-        }
-    }
-    impl From<Button> for GamepadInput {
-        fn from(value: Button) -> Self {
-            match value {
-                Button::South => GamepadInput::South,
-                Button::East => GamepadInput::East,
-                Button::North => GamepadInput::North,
-                Button::West => GamepadInput::West,
-                Button::LeftTrigger => GamepadInput::LeftBumper,
-                Button::LeftTrigger2 => GamepadInput::LeftTrigger,
-                Button::RightTrigger2 => GamepadInput::RightTrigger,
-                Button::RightTrigger => GamepadInput::RightBumper,
-                Button::DPadUp => GamepadInput::DPadUp,
-                Button::DPadDown => GamepadInput::DPadDown,
-                Button::DPadLeft => GamepadInput::DPadLeft,
-                Button::DPadRight => GamepadInput::DPadRight,
-                Button::Z => GamepadInput::RightZ,
-                Button::C => GamepadInput::LeftZ,
-                Button::Select => GamepadInput::Select,
-                Button::Start => GamepadInput::Start,
-                Button::Mode => GamepadInput::Mode,
-                Button::RightThumb => GamepadInput::RightStickPress,
-                Button::LeftThumb  => GamepadInput::LeftStickPress,
-                Button::Unknown => GamepadInput::Other 
-            }
-        }
-    }
-    impl From<GamepadInput> for InputCode {
-        fn from(value: GamepadInput) -> InputCode {
-            Self::Gamepad { input: value, id: Default::default() }
-        }
-    }
-    /// Specify gamepad to listen to. defaults to any and can be specified later on at runtime
-    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
-    pub enum SpecifyGamepad {
-        /// cant be set at compile time. use `Any` as default and then let the user select a specific
-        /// gamepad at runtime
-        Id(gilrs::GamepadId),
-        /// use as default
-        #[default]
-        Any
-    }
-}
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Enum that specifies an input. Like everything else that converts into it, it can be used
+/// directly in `binds!` and `input_map!` since the standard library already gives every type a
+/// `From` for itself.
+pub enum InputCode {
+    #[cfg(feature = "mice-keyboard")]
+    Device { id: SpecifyDevice, input: DeviceInput },
+    #[cfg(feature = "gamepad")]
+    Gamepad { id: SpecifyGamepad, input: GamepadInput }
+}
+impl InputCode {
+    /// sets `SpecifyGamepad` or `SpecifyDevice` to any
+    pub fn set_any(self) -> Self {
+        match self {
+            #[cfg(feature = "gamepad")]
+            Self::Gamepad { input, .. } => input.into(),
+            #[cfg(feature = "mice-keyboard")]
+            Self::Device  { input, .. } => input.into(),
+        }
+    }
+    pub fn is_any(self) -> bool {
+        match self {
+            #[cfg(feature = "gamepad")]
+            Self::Gamepad { id, .. } => id == SpecifyGamepad::Any,
+            #[cfg(feature = "mice-keyboard")]
+            Self::Device  { id, .. } => id == SpecifyDevice::Any,
+        }
+    }
+    /// checks if its mouse movement
+    pub fn is_mouse_move(self) -> bool {
+        match self {
+            #[cfg(feature = "gamepad")]
+            Self::Gamepad { .. } => false,
+            #[cfg(feature = "mice-keyboard")]
+            Self::Device { input, .. } => {
+                use DeviceInput::*;
+                matches!(input, MouseMove | MouseMoveLeft | MouseMoveRight | MouseMoveUp | MouseMoveDown)
+            }
+        }
+    }
+    /// the kind of device the input is from
+    pub fn kind(self) -> InputKind {
+        match self {
+            #[cfg(feature = "gamepad")]
+            Self::Gamepad { .. } => InputKind::Gamepad,
+            #[cfg(feature = "mice-keyboard")]
+            Self::Device  { .. } => InputKind::MouseKeyboard,
+        }
+    }
+    /// checks if its an input that stands in for others, like `AnyKey` or `AnyButton`
+    pub fn is_meta(self) -> bool {
+        match self {
+            #[cfg(feature = "gamepad")]
+            Self::Gamepad { input, .. } => input == GamepadInput::AnyButton,
+            #[cfg(feature = "mice-keyboard")]
+            Self::Device  { input, .. } => input == DeviceInput::AnyKey,
+        }
+    }
+    #[cfg(feature = "mice-keyboard")]
+    #[allow(unreachable_patterns)]
+    pub fn has_device_id(&self, id: winit::event::DeviceId) -> bool {
+        match self {
+            Self::Device { id: SpecifyDevice::Id(cid), .. } => *cid == id,
+            Self::Device { id: SpecifyDevice::Any,     .. } => true,
+            _ => false
+        }
+    }
+    #[cfg(feature = "gamepad")]
+    #[allow(unreachable_patterns)]
+    pub fn has_gamepad_id(&self, id: gilrs::GamepadId) -> bool {
+        match self {
+            Self::Gamepad { id: SpecifyGamepad::Id(cid), .. } => *cid == id,
+            Self::Gamepad { id: SpecifyGamepad::Any,     .. } => true,
+            _ => false
+        }
+    }
+    #[cfg(feature = "gamepad")]
+    #[allow(irrefutable_let_patterns)]
+    /// sets the gamepad id. if its a device it does nothing.
+    pub fn set_gamepad_id(self, id: gilrs::GamepadId) -> Self {
+        if let Self::Gamepad { input, .. } = self { input.with_id(id) }
+        else { self }
+    }
+    #[cfg(feature = "mice-keyboard")]
+    #[allow(irrefutable_let_patterns)]
+    /// sets the device id. if its a gamepad it does nothing.
+    pub fn set_device_id(self, id: winit::event::DeviceId) -> Self {
+        if let Self::Device { input, .. } = self { input.with_id(id) }
+        else { self }
+    }
+}
+/// Shows the input like "Left Shift" or "D-Pad Up" for rebinding menus and button prompts. The
+/// device or gamepad isn't included. Gamepad inputs with the same name as a key, like "Select",
+/// start with "Gamepad" so the name can always be parsed back into the same input code.
+impl std::fmt::Display for InputCode {
+    // there is nothing to write to with both features off as there are no input codes
+    #[cfg_attr(not(any(feature = "mice-keyboard", feature = "gamepad")), allow(unused_variables))]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // dereferenced so the match is still exhaustive when both features are off
+        match *self {
+            #[cfg(feature = "mice-keyboard")]
+            Self::Device  { input, .. } => input.fmt(f),
+            #[cfg(all(feature = "gamepad", feature = "mice-keyboard"))]
+            Self::Gamepad { input, .. } if input.to_string().parse::<DeviceInput>().is_ok() => {
+                write!(f, "Gamepad {input}")
+            },
+            #[cfg(feature = "gamepad")]
+            Self::Gamepad { input, .. } => input.fmt(f),
+        }
+    }
+}
+/// Reads an input code from its name, either the name of its variant like "KeyW", "MouseLeft" and
+/// "South" or the name its shown with like "Left Shift" and "A Button". Case and spaces are
+/// ignored. Names used by both a key and a gamepad input, like "Select", are read as the key
+/// unless they start with "Gamepad", e.g. "GamepadSelect". Single characters that aren't the name
+/// of a key, or that start with "Char" like "Char A", are read as `DeviceInput::Char`.
+impl std::str::FromStr for InputCode {
+    type Err = UnknownInputCode;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[cfg(feature = "mice-keyboard")]
+        if let Ok(input) = s.parse::<DeviceInput>() { return Ok(input.into()) }
+        #[cfg(feature = "gamepad")]
+        if let Ok(input) = s.parse::<GamepadInput>() { return Ok(input.into()) }
+        Err(UnknownInputCode(s.to_string()))
+    }
+}
+/// The error for parsing a name that isn't the name of an input code
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct UnknownInputCode(pub String);
+impl std::fmt::Display for UnknownInputCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` isn't the name of an input code", self.0)
+    }
+}
+impl std::error::Error for UnknownInputCode {}
+/// lowercases the name and removes spaces so names can be compared loosely
+#[cfg(any(feature = "mice-keyboard", feature = "gamepad"))]
+fn normalise(name: &str) -> String {
+    name.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase).collect()
+}
+/// The kind of device an input came from, see `InputMap::active_input_kind`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum InputKind {
+    #[cfg_attr(feature = "mice-keyboard", default)]
+    MouseKeyboard,
+    #[cfg_attr(not(feature = "mice-keyboard"), default)]
+    Gamepad
+}
+/// what `SpecifyDevice` and `SpecifyGamepad` are saved as
+#[cfg(all(feature = "serde", any(feature = "mice-keyboard", feature = "gamepad")))]
+#[derive(serde::Serialize, serde::Deserialize)]
+enum AnyId { Any }
+/// imports everything needed to reduce boilerplate when creating an input_map
+pub mod base_input_codes {
+    #![allow(ambiguous_glob_reexports)]
+    #[cfg(any(feature = "mice-keyboard", feature = "gamepad"))]
+    use crate::input_code::*;
+
+    #[cfg(feature = "gamepad")]
+    pub use gamepad::GamepadInput::{*, self};
+
+    #[cfg(feature = "mice-keyboard")]
+    pub use mice_keyboard::DeviceInput::{*, self};
+
+    #[cfg(feature = "mice-keyboard")]
+    pub use winit::{
+        keyboard::{KeyCode::{*, self}, PhysicalKey::{*, self}},
+        event::MouseButton
+    };
+}
+
+#[cfg(feature = "mice-keyboard")]
+pub use mice_keyboard::*;
+#[cfg(feature = "mice-keyboard")]
+mod mice_keyboard {
+    use winit::keyboard::{ KeyCode, PhysicalKey };
+    use winit::event::*;
+    use crate::InputCode;
+    #[cfg(feature = "mice-keyboard")]
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum DeviceInput {
+        Button(MouseButton),
+        Key(PhysicalKey),
+        /// the character a key types, so binds work the same on every keyboard layout. Letters
+        /// are lowercase so shift doesn't change them. Useful for shortcuts like `Ctrl + /`
+        Char(char),
+        /// pressed while any key is held, useful for "press any key" prompts
+        AnyKey,
+        /// how hard a force touch trackpad is being pressed from 0 to 1. Only works on macOS
+        TouchpadPressure,
+        /// how far the mouse moved in any direction
+        MouseMove,
+        MouseMoveLeft,
+        MouseMoveRight,
+        MouseMoveUp,
+        MouseMoveDown,
+        MouseScrollUp,
+        MouseScrollDown,
+        MouseScrollLeft,
+        MouseScrollRight,
+    }
+    #[cfg(feature = "mice-keyboard")]
+    impl DeviceInput {
+        pub fn with_id(self, id: DeviceId) -> InputCode {
+            InputCode::Device { id: SpecifyDevice::Id(id), input: self }
+        }
+        pub fn with_sid(self, id: SpecifyDevice) -> InputCode {
+            InputCode::Device { id, input: self }
+        }
+    }
+    #[cfg(feature = "mice-keyboard")]
+    impl From<MouseButton> for DeviceInput {
+        fn from(value: MouseButton) -> Self {
+            Self::Button(value) 
+        }
+    }
+    #[cfg(feature = "mice-keyboard")]
+    impl From<KeyCode> for DeviceInput {
+        fn from(value: KeyCode) -> Self {
+            Self::Key(value.into()) 
+        }
+    }
+    #[cfg(feature = "mice-keyboard")]
+    impl From<char> for DeviceInput {
+        fn from(value: char) -> Self {
+            Self::Char(value)
+        }
+    }
+    #[cfg(feature = "mice-keyboard")]
+    impl From<PhysicalKey> for DeviceInput {
+        fn from(value: PhysicalKey) -> Self {
+            Self::Key(value)
+        }
+    }
+    /// Shows the input like "Mouse Left" or "Space". Uncommon keys use their `KeyCode` name.
+    /// Characters are uppercase and start with "Char" when they would be read as a key, like
+    /// "Char A" and "Char /", so the name can always be parsed back into the same input.
+    impl std::fmt::Display for DeviceInput {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let name = match self {
+                Self::Button(MouseButton::Left) => "Mouse Left",
+                Self::Button(MouseButton::Right) => "Mouse Right",
+                Self::Button(MouseButton::Middle) => "Mouse Middle",
+                Self::Button(MouseButton::Back) => "Mouse Back",
+                Self::Button(MouseButton::Forward) => "Mouse Forward",
+                Self::Button(MouseButton::Other(i)) => return write!(f, "Mouse {i}"),
+                Self::Key(PhysicalKey::Code(key)) => return key_name(*key, f),
+                Self::Key(PhysicalKey::Unidentified(_)) => "Unknown Key",
+                Self::Char(c) => {
+                    let name = c.to_uppercase().to_string();
+                    return if name.parse() == Ok(*self) { f.write_str(&name) } else { write!(f, "Char {name}") }
+                },
+                Self::AnyKey => "Any Key",
+                Self::TouchpadPressure => "Touchpad Pressure",
+                Self::MouseMove => "Mouse Move",
+                Self::MouseMoveLeft => "Mouse Move Left",
+                Self::MouseMoveRight => "Mouse Move Right",
+                Self::MouseMoveUp => "Mouse Move Up",
+                Self::MouseMoveDown => "Mouse Move Down",
+                Self::MouseScrollUp => "Scroll Up",
+                Self::MouseScrollDown => "Scroll Down",
+                Self::MouseScrollLeft => "Scroll Left",
+                Self::MouseScrollRight => "Scroll Right",
+            };
+            f.write_str(name)
+        }
+    }
+    /// Reads the input from its name, see `InputCode`s `FromStr`
+    impl std::str::FromStr for DeviceInput {
+        type Err = super::UnknownInputCode;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            use DeviceInput::*;
+            let name = super::normalise(s);
+            if let Some(i) = name.strip_prefix("mouse").and_then(|i| i.parse().ok()) {
+                return Ok(Button(MouseButton::Other(i)))
+            }
+            let mut chars = name.strip_prefix("char").unwrap_or_default().chars();
+            if let (Some(c), None) = (chars.next(), chars.next()) { return Ok(Char(c)) }
+            let keys = ALL_KEYS.map(|k| (format!("{k:?}"), k.into()));
+            let buttons = [MouseButton::Left, MouseButton::Right, MouseButton::Middle, MouseButton::Back, MouseButton::Forward]
+                .map(|b| (format!("Mouse{b:?}"), b.into()));
+            let others = [AnyKey, TouchpadPressure, MouseMove, MouseMoveLeft, MouseMoveRight, MouseMoveUp,
+                MouseMoveDown, MouseScrollUp, MouseScrollDown, MouseScrollLeft, MouseScrollRight]
+                .map(|i| (format!("{i:?}"), i));
+            let found = keys.into_iter().chain(buttons).chain(others).find(|(variant, input)| {
+                super::normalise(variant) == name || super::normalise(&input.to_string()) == name
+            });
+            if let Some((_, input)) = found { return Ok(input) }
+
+            let mut chars = s.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(Char(c.to_lowercase().next().unwrap_or(c))),
+                _ => Err(super::UnknownInputCode(s.to_string()))
+            }
+        }
+    }
+    /// every `KeyCode`, for parsing them from their names. `KeyCode` is non exhaustive so keys
+    /// added by newer versions of winit have to be added here by hand, until they are they're shown
+    /// with their `KeyCode` name but can't be parsed. The `all_keys` test checks every key here
+    /// can be parsed back from its name.
+    pub(crate) const ALL_KEYS: [KeyCode; 194] = {
+        use KeyCode::*;
+        [
+            Backquote, Backslash, BracketLeft, BracketRight, Comma, Digit0, Digit1, Digit2, Digit3,
+            Digit4, Digit5, Digit6, Digit7, Digit8, Digit9, Equal, IntlBackslash, IntlRo, IntlYen, KeyA,
+            KeyB, KeyC, KeyD, KeyE, KeyF, KeyG, KeyH, KeyI, KeyJ, KeyK, KeyL, KeyM, KeyN, KeyO, KeyP,
+            KeyQ, KeyR, KeyS, KeyT, KeyU, KeyV, KeyW, KeyX, KeyY, KeyZ, Minus, Period, Quote, Semicolon,
+            Slash, AltLeft, AltRight, Backspace, CapsLock, ContextMenu, ControlLeft, ControlRight,
+            Enter, SuperLeft, SuperRight, ShiftLeft, ShiftRight, Space, Tab, Convert, KanaMode, Lang1,
+            Lang2, Lang3, Lang4, Lang5, NonConvert, Delete, End, Help, Home, Insert, PageDown, PageUp,
+            ArrowDown, ArrowLeft, ArrowRight, ArrowUp, NumLock, Numpad0, Numpad1, Numpad2, Numpad3,
+            Numpad4, Numpad5, Numpad6, Numpad7, Numpad8, Numpad9, NumpadAdd, NumpadBackspace,
+            NumpadClear, NumpadClearEntry, NumpadComma, NumpadDecimal, NumpadDivide, NumpadEnter,
+            NumpadEqual, NumpadHash, NumpadMemoryAdd, NumpadMemoryClear, NumpadMemoryRecall,
+            NumpadMemoryStore, NumpadMemorySubtract, NumpadMultiply, NumpadParenLeft, NumpadParenRight,
+            NumpadStar, NumpadSubtract, Escape, Fn, FnLock, PrintScreen, ScrollLock, Pause, BrowserBack,
+            BrowserFavorites, BrowserForward, BrowserHome, BrowserRefresh, BrowserSearch, BrowserStop,
+            Eject, LaunchApp1, LaunchApp2, LaunchMail, MediaPlayPause, MediaSelect, MediaStop,
+            MediaTrackNext, MediaTrackPrevious, Power, Sleep, AudioVolumeDown, AudioVolumeMute,
+            AudioVolumeUp, WakeUp, Meta, Hyper, Turbo, Abort, Resume, Suspend, Again, Copy, Cut, Find,
+            Open, Paste, Props, Select, Undo, Hiragana, Katakana, F1, F2, F3, F4, F5, F6, F7, F8, F9,
+            F10, F11, F12, F13, F14, F15, F16, F17, F18, F19, F20, F21, F22, F23, F24, F25, F26, F27,
+            F28, F29, F30, F31, F32, F33, F34, F35
+        ]
+    };
+    fn key_name(key: KeyCode, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use KeyCode::*;
+        let name = match key {
+            Space => "Space",
+            Enter => "Enter",
+            Escape => "Escape",
+            Backspace => "Backspace",
+            Tab => "Tab",
+            CapsLock => "Caps Lock",
+            ShiftLeft => "Left Shift",
+            ShiftRight => "Right Shift",
+            ControlLeft => "Left Ctrl",
+            ControlRight => "Right Ctrl",
+            AltLeft => "Left Alt",
+            AltRight => "Right Alt",
+            SuperLeft => "Left Super",
+            SuperRight => "Right Super",
+            ContextMenu => "Menu",
+            ArrowUp => "Up Arrow",
+            ArrowDown => "Down Arrow",
+            ArrowLeft => "Left Arrow",
+            ArrowRight => "Right Arrow",
+            Insert => "Insert",
+            Delete => "Delete",
+            Home => "Home",
+            End => "End",
+            PageUp => "Page Up",
+            PageDown => "Page Down",
+            PrintScreen => "Print Screen",
+            ScrollLock => "Scroll Lock",
+            Pause => "Pause",
+            NumLock => "Num Lock",
+            Backquote => "`",
+            Minus => "-",
+            Equal => "=",
+            BracketLeft => "[",
+            BracketRight => "]",
+            Backslash => "\\",
+            Semicolon => ";",
+            Quote => "'",
+            Comma => ",",
+            Period => ".",
+            Slash => "/",
+            NumpadAdd => "Numpad +",
+            NumpadSubtract => "Numpad -",
+            NumpadMultiply => "Numpad *",
+            NumpadDivide => "Numpad /",
+            NumpadDecimal => "Numpad .",
+            NumpadEnter => "Numpad Enter",
+            _ => {
+                // `KeyA` becomes `A`, `Digit1` becomes `1` and `Numpad1` becomes `Numpad 1`
+                let name = format!("{key:?}");
+                if let Some(rest) = name.strip_prefix("Key").or(name.strip_prefix("Digit")) {
+                    return f.write_str(rest)
+                }
+                if let Some(rest) = name.strip_prefix("Numpad").filter(|r| r.len() == 1) {
+                    return write!(f, "Numpad {rest}")
+                }
+                return f.write_str(&name)
+            }
+        };
+        f.write_str(name)
+    }
+    /// The number keys along the top of the keyboard in the order they appear, 1 to 9 then 0.
+    /// Useful for hotbars.
+    /// ```ignore
+    /// for (slot, key) in digit_row().into_iter().enumerate() {
+    ///     input.add_binds(&vec![(Action::Slot(slot), vec![vec![key]])]);
+    /// }
+    /// ```
+    pub fn digit_row() -> [InputCode; 10] {
+        use KeyCode::*;
+        [Digit1, Digit2, Digit3, Digit4, Digit5, Digit6, Digit7, Digit8, Digit9, Digit0].map(Into::into)
+    }
+    /// The function keys from F1 to F12.
+    pub fn function_keys() -> [InputCode; 12] {
+        use KeyCode::*;
+        [F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12].map(Into::into)
+    }
+    /// The direction of a touch swipe, see `InputMap::take_swipes`
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+    pub enum SwipeDirection {
+        Left,
+        Right,
+        Up,
+        Down
+    }
+    /// specify device to listen to. defaults to any and can be specified later on at runtime. With
+    /// the `serde` feature it's always saved as `Any`, since ids change every time the program runs
+    #[cfg(feature = "mice-keyboard")]
+    #[derive(Default, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+    pub enum SpecifyDevice {
+        /// cant be set at compile time. use `Any` as default and then let the user select a specific
+        /// gamepad at runtime
+        Id(DeviceId),
+        /// use as default
+        #[default]
+        Any
+    }
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for SpecifyDevice {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serde::Serialize::serialize(&super::AnyId::Any, serializer)
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for SpecifyDevice {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            <super::AnyId as serde::Deserialize>::deserialize(deserializer).map(|_| Self::Any)
+        }
+    }
+    impl From<DeviceInput> for InputCode {
+        fn from(value: DeviceInput) -> Self {
+            Self::Device { id: SpecifyDevice::Any, input: value }
+        }
+    }
+    impl From<MouseButton> for InputCode {
+        fn from(value: MouseButton) -> Self {
+            Self::Device { id: SpecifyDevice::Any, input: value.into() }
+        }
+    }
+    impl From<PhysicalKey> for InputCode {
+        fn from(value: PhysicalKey) -> Self {
+            Self::Device { id: SpecifyDevice::Any, input: value.into() }
+        }
+    }
+    impl From<KeyCode> for InputCode {
+        fn from(value: KeyCode) -> Self {
+            Self::Device { id: SpecifyDevice::Any, input: value.into() }
+        }
+    }
+    impl From<char> for InputCode {
+        fn from(value: char) -> Self {
+            Self::Device { id: SpecifyDevice::Any, input: value.into() }
+        }
+    }
+}
+
+
+#[cfg(feature = "gamepad")]
+pub use gamepad::*;
+#[cfg(feature = "gamepad")]
+mod gamepad {
+    use crate::InputCode;
+    use gilrs::{Axis, Button};
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum GamepadInput {
+        LeftStickLeft,
+        /// the left stick, moved to the right
+        LeftStickRight,
+        LeftStickUp,
+        LeftStickDown,
+        LeftStickPress,
+
+        /// the right stick, moved to the left
+        RightStickLeft,
+        RightStickRight,
+        RightStickUp,
+        RightStickDown,
+        RightStickPress,
+
+        DPadLeft,
+        DPadRight,
+        DPadUp,
+        DPadDown,
+
+        LeftZ,
+        RightZ,
+
+        South,
+        East,
+        North,
+        West,
+
+        LeftBumper,
+        LeftTrigger,
+        RightBumper,
+        RightTrigger,
+
+        Select,
+        Start,
+        Mode,
+
+        /// gyroscope rotation rates, see `InputMap::update_gyro`
+        GyroPitchUp,
+        GyroPitchDown,
+        GyroYawLeft,
+        GyroYawRight,
+        GyroRollLeft,
+        GyroRollRight,
+        /// how much the most pressed button of the gamepad is pressed, useful for "press any
+        /// button" prompts. Doesn't include the sticks
+        AnyButton,
+        /// unfortunately gilrs doesnt give enough infomation to have multiple 'Other' input binds
+        Other
+    }
+    /// Shows the input like "D-Pad Up". The face buttons use the letters of an Xbox controller, see
+    /// `GamepadKind` for telling which gamepad is being used.
+    impl std::fmt::Display for GamepadInput {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            use GamepadInput::*;
+            f.write_str(match self {
+                LeftStickLeft => "Left Stick Left",
+                LeftStickRight => "Left Stick Right",
+                LeftStickUp => "Left Stick Up",
+                LeftStickDown => "Left Stick Down",
+                LeftStickPress => "Left Stick Press",
+                RightStickLeft => "Right Stick Left",
+                RightStickRight => "Right Stick Right",
+                RightStickUp => "Right Stick Up",
+                RightStickDown => "Right Stick Down",
+                RightStickPress => "Right Stick Press",
+                DPadLeft => "D-Pad Left",
+                DPadRight => "D-Pad Right",
+                DPadUp => "D-Pad Up",
+                DPadDown => "D-Pad Down",
+                LeftZ => "Left Z",
+                RightZ => "Right Z",
+                South => "A Button",
+                East => "B Button",
+                North => "Y Button",
+                West => "X Button",
+                LeftBumper => "Left Bumper",
+                LeftTrigger => "Left Trigger",
+                RightBumper => "Right Bumper",
+                RightTrigger => "Right Trigger",
+                Select => "Select",
+                Start => "Start",
+                Mode => "Mode",
+                GyroPitchUp => "Gyro Pitch Up",
+                GyroPitchDown => "Gyro Pitch Down",
+                GyroYawLeft => "Gyro Yaw Left",
+                GyroYawRight => "Gyro Yaw Right",
+                GyroRollLeft => "Gyro Roll Left",
+                GyroRollRight => "Gyro Roll Right",
+                AnyButton => "Any Button",
+                Other => "Other Button",
+            })
+        }
+    }
+    /// Reads the input from its name, which can start with "Gamepad". See `InputCode`s `FromStr`
+    impl std::str::FromStr for GamepadInput {
+        type Err = super::UnknownInputCode;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            use GamepadInput::*;
+            let name = super::normalise(s);
+            let name = name.strip_prefix("gamepad").unwrap_or(&name);
+            [LeftStickLeft, LeftStickRight, LeftStickUp, LeftStickDown, LeftStickPress, RightStickLeft,
+             RightStickRight, RightStickUp, RightStickDown, RightStickPress, DPadLeft, DPadRight, DPadUp,
+             DPadDown, LeftZ, RightZ, South, East, North, West, LeftBumper, LeftTrigger, RightBumper,
+             RightTrigger, Select, Start, Mode, GyroPitchUp, GyroPitchDown, GyroYawLeft, GyroYawRight,
+             GyroRollLeft, GyroRollRight, AnyButton, Other].into_iter()
+                .find(|i| super::normalise(&format!("{i:?}")) == name || super::normalise(&i.to_string()) == name)
+                .ok_or_else(|| super::UnknownInputCode(s.to_string()))
+        }
+    }
+    impl GamepadInput {
+        pub fn with_id(self, id: gilrs::GamepadId) -> InputCode {
+            InputCode::Gamepad { id: SpecifyGamepad::Id(id), input: self }
+        }
+        pub fn with_sid(self, id: SpecifyGamepad) -> InputCode {
+            InputCode::Gamepad { id, input: self }
+        }
+    }
+    pub fn axis_neg(axis: Axis) -> GamepadInput {
+        match axis {
+            Axis::LeftStickX => GamepadInput::LeftStickLeft,
+            Axis::LeftStickY => GamepadInput::LeftStickDown,
+            Axis::RightStickX => GamepadInput::RightStickLeft,
+            Axis::RightStickY => GamepadInput::RightStickDown,
+            Axis::LeftZ => GamepadInput::LeftZ,
+            Axis::RightZ => GamepadInput::RightZ,
+            Axis::DPadX => GamepadInput::DPadLeft,
+            Axis::DPadY => GamepadInput::DPadDown,
+            Axis::Unknown => GamepadInput::Other
+        }
+    }
+    pub fn axis_pos(axis: Axis) -> GamepadInput {
+        match axis {
+            Axis::LeftStickX => GamepadInput::LeftStickRight,
+            Axis::LeftStickY => GamepadInput::LeftStickUp,
+            Axis::RightStickX => GamepadInput::RightStickRight,
+            Axis::RightStickY => GamepadInput::RightStickUp,
+            Axis::LeftZ => GamepadInput::LeftZ,
+            Axis::RightZ => GamepadInput::RightZ,
+            Axis::DPadX => GamepadInput::DPadRight,
+            Axis::DPadY => GamepadInput::DPadUp,
+            Axis::Unknown => GamepadInput::Other,
+        }
+    }
+    impl From<Button> for GamepadInput {
+        fn from(value: Button) -> Self {
+            match value {
+                Button::South => GamepadInput::South,
+                Button::East => GamepadInput::East,
+                Button::North => GamepadInput::North,
+                Button::West => GamepadInput::West,
+                Button::LeftTrigger => GamepadInput::LeftBumper,
+                Button::LeftTrigger2 => GamepadInput::LeftTrigger,
+                Button::RightTrigger2 => GamepadInput::RightTrigger,
+                Button::RightTrigger => GamepadInput::RightBumper,
+                Button::DPadUp => GamepadInput::DPadUp,
+                Button::DPadDown => GamepadInput::DPadDown,
+                Button::DPadLeft => GamepadInput::DPadLeft,
+                Button::DPadRight => GamepadInput::DPadRight,
+                Button::Z => GamepadInput::RightZ,
+                Button::C => GamepadInput::LeftZ,
+                Button::Select => GamepadInput::Select,
+                Button::Start => GamepadInput::Start,
+                Button::Mode => GamepadInput::Mode,
+                Button::RightThumb => GamepadInput::RightStickPress,
+                Button::LeftThumb  => GamepadInput::LeftStickPress,
+                Button::Unknown => GamepadInput::Other 
+            }
+        }
+    }
+    impl From<GamepadInput> for InputCode {
+        fn from(value: GamepadInput) -> InputCode {
+            Self::Gamepad { input: value, id: Default::default() }
+        }
+    }
+    /// The family a gamepad belongs to. Useful for showing the right button prompts
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+    pub enum GamepadKind {
+        Xbox,
+        PlayStation,
+        Nintendo,
+        /// used when the gamepad can't be recognised
+        #[default]
+        Generic
+    }
+    impl From<gilrs::Gamepad<'_>> for GamepadKind {
+        fn from(gamepad: gilrs::Gamepad) -> Self {
+            Self::detect(gamepad.vendor_id(), gamepad.name())
+        }
+    }
+    impl GamepadKind {
+        /// uses the usb vendor id if its known, otherwise looks for the brand in the name
+        pub(crate) fn detect(vendor_id: Option<u16>, name: &str) -> Self {
+            match vendor_id {
+                Some(0x045e) => return Self::Xbox,
+                Some(0x054c) => return Self::PlayStation,
+                Some(0x057e) => return Self::Nintendo,
+                _ => ()
+            }
+            let name = name.to_lowercase();
+            let has = |names: &[&str]| names.iter().any(|n| name.contains(n));
+            if has(&["xbox", "xinput"]) { Self::Xbox }
+            else if has(&["playstation", "dualshock", "dualsense", "ps3", "ps4", "ps5"]) { Self::PlayStation }
+            else if has(&["nintendo", "switch", "joy-con", "pro controller"]) { Self::Nintendo }
+            else { Self::Generic }
+        }
+    }
+    /// Specify gamepad to listen to. defaults to any and can be specified later on at runtime. With
+    /// the `serde` feature it's always saved as `Any`, since ids change every time the program runs
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+    pub enum SpecifyGamepad {
+        /// cant be set at compile time. use `Any` as default and then let the user select a specific
+        /// gamepad at runtime
+        Id(gilrs::GamepadId),
+        /// use as default
+        #[default]
+        Any
+    }
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for SpecifyGamepad {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serde::Serialize::serialize(&super::AnyId::Any, serializer)
+        }
+    }
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for SpecifyGamepad {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            <super::AnyId as serde::Deserialize>::deserialize(deserializer).map(|_| Self::Any)
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::input::*;
+use std::{cmp::Eq, hash::Hash};
+/// A list of named binds, letting players save multiple control schemes and switch between them.
+/// ```
+/// use winit_input_map::*;
+/// #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+/// enum Action { Jump }
+///
+/// let mut input = input_map!((Action::Jump, base_input_codes::Space));
+/// let mut profiles = Profiles::new();
+/// profiles.add("default", input.get_binds());
+/// profiles.add("southpaw", binds!((Action::Jump, base_input_codes::KeyJ)));
+///
+/// profiles.apply("southpaw", &mut input);
+/// assert_eq!(profiles.current(), Some("southpaw"));
+/// ```
+/// With the `serde` feature profiles can be saved along with which one is current.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Profiles<F> {
+    profiles: Vec<(String, Binds<F>)>,
+    current: Option<usize>
+}
+impl<F> Default for Profiles<F> {
+    fn default() -> Self {
+        Self { profiles: Vec::new(), current: None }
+    }
+}
+impl<F: Hash + Copy + Eq> Profiles<F> {
+    pub fn new() -> Self { Self::default() }
+    fn position(&self, name: &str) -> Option<usize> {
+        self.profiles.iter().position(|(n, _)| n == name)
+    }
+    /// Adds a profile, replacing the binds of the profile with the same name if there is one.
+    pub fn add(&mut self, name: impl Into<String>, binds: Binds<F>) {
+        let name = name.into();
+        if let Some(i) = self.position(&name) { self.profiles[i].1 = binds }
+        else { self.profiles.push((name, binds)) }
+    }
+    /// Removes a profile and returns its binds. If it was the current profile there will no
+    /// longer be a current profile.
+    pub fn remove(&mut self, name: &str) -> Option<Binds<F>> {
+        let i = self.position(name)?;
+        self.current = match self.current {
+            Some(c) if c == i => None,
+            Some(c) if c > i => Some(c - 1),
+            c => c
+        };
+        Some(self.profiles.remove(i).1)
+    }
+    /// Renames a profile. Returns false if there is no profile called `old` or another profile
+    /// is already called `new`.
+    pub fn rename(&mut self, old: &str, new: impl Into<String>) -> bool {
+        let new = new.into();
+        let Some(i) = self.position(old) else { return false };
+        if self.position(&new).is_some_and(|j| j != i) { return false }
+        self.profiles[i].0 = new;
+        true
+    }
+    /// Returns the binds of a profile.
+    pub fn get(&self, name: &str) -> Option<&Binds<F>> {
+        self.position(name).map(|i| &self.profiles[i].1)
+    }
+    /// Returns the names of every profile in the order they were added.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.iter().map(|(n, _)| n.as_str())
+    }
+    /// The name of the last applied profile.
+    pub fn current(&self) -> Option<&str> {
+        // loaded profiles could point past the end
+        self.current.and_then(|i| self.profiles.get(i)).map(|(n, _)| n.as_str())
+    }
+    /// Sets the binds of the input map to the profile and makes it the current profile. Returns
+    /// false if there is no profile with that name.
+    pub fn apply(&mut self, name: &str, input: &mut InputMap<F>) -> bool {
+        let Some(i) = self.position(name) else { return false };
+        input.set_binds(&self.profiles[i].1);
+        self.current = Some(i);
+        true
+    }
+}